@@ -112,3 +112,14 @@ fn thread_yield_multi_thread(b: &mut Bencher) {
         y.wait().unwrap();
     });
 }
+
+#[bench]
+fn thread_sequential_waits(b: &mut Bencher) {
+    const NUM: usize = 100_000;
+
+    b.iter(|| {
+        for _ in 0..NUM {
+            futures::future::ok::<u32, ()>(1).wait().unwrap();
+        }
+    });
+}
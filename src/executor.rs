@@ -11,6 +11,9 @@
 #[cfg(feature = "use_std")]
 pub use task_impl::{Unpark, Executor, Run};
 
+#[cfg(feature = "use_std")]
+pub use task_impl::TestExecutor;
+
 pub use task_impl::{Spawn, spawn, Notify, with_notify};
 
 pub use task_impl::{UnsafeNotify, NotifyHandle};
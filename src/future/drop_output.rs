@@ -0,0 +1,28 @@
+use {Future, Poll, Async};
+
+/// Future for the `drop_output` combinator, discarding the item of a future.
+///
+/// This is created by the `Future::drop_output` method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct DropOutput<A> where A: Future {
+    future: A,
+}
+
+pub fn new<A>(future: A) -> DropOutput<A>
+    where A: Future
+{
+    DropOutput {
+        future: future,
+    }
+}
+
+impl<A: Future> Future for DropOutput<A> {
+    type Item = ();
+    type Error = A::Error;
+
+    fn poll(&mut self) -> Poll<(), A::Error> {
+        try_ready!(self.future.poll());
+        Ok(Async::Ready(()))
+    }
+}
@@ -1,7 +1,8 @@
 use {Future, Poll};
+use stream::Stream;
 
-/// Combines two different futures yielding the same item and error
-/// types into a single type.
+/// Combines two different futures, or streams, yielding the same item and
+/// error types into a single type.
 #[derive(Debug)]
 pub enum Either<A, B> {
     /// First branch of the type
@@ -37,3 +38,18 @@ impl<A, B> Future for Either<A, B>
         }
     }
 }
+
+impl<A, B> Stream for Either<A, B>
+    where A: Stream,
+          B: Stream<Item = A::Item, Error = A::Error>
+{
+    type Item = A::Item;
+    type Error = A::Error;
+
+    fn poll(&mut self) -> Poll<Option<A::Item>, A::Error> {
+        match *self {
+            Either::A(ref mut a) => a.poll(),
+            Either::B(ref mut b) => b.poll(),
+        }
+    }
+}
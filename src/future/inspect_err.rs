@@ -0,0 +1,40 @@
+use {Future, Poll, Async};
+
+/// Do something with the error of a future, passing it on.
+///
+/// This is created by the `Future::inspect_err` method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct InspectErr<A, F> where A: Future {
+    future: A,
+    f: Option<F>,
+}
+
+pub fn new<A, F>(future: A, f: F) -> InspectErr<A, F>
+    where A: Future,
+          F: FnOnce(&A::Error),
+{
+    InspectErr {
+        future: future,
+        f: Some(f),
+    }
+}
+
+impl<A, F> Future for InspectErr<A, F>
+    where A: Future,
+          F: FnOnce(&A::Error),
+{
+    type Item = A::Item;
+    type Error = A::Error;
+
+    fn poll(&mut self) -> Poll<A::Item, A::Error> {
+        match self.future.poll() {
+            Ok(Async::NotReady) => Ok(Async::NotReady),
+            Ok(Async::Ready(e)) => Ok(Async::Ready(e)),
+            Err(e) => {
+                (self.f.take().expect("cannot poll InspectErr twice"))(&e);
+                Err(e)
+            },
+        }
+    }
+}
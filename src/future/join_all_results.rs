@@ -0,0 +1,114 @@
+//! Definition of the `JoinAllResults` combinator, waiting for all of a list of
+//! futures to finish regardless of whether they succeed.
+
+use std::prelude::v1::*;
+
+use std::fmt;
+use std::mem;
+
+use {Future, IntoFuture, Poll, Async};
+
+#[derive(Debug)]
+enum ElemState<T> where T: Future {
+    Pending(T),
+    Done(Result<T::Item, T::Error>),
+}
+
+/// A future which takes a list of futures and resolves with a vector of the
+/// results of each of them.
+///
+/// This future is created with the `join_all_results` method.
+#[must_use = "futures do nothing unless polled"]
+pub struct JoinAllResults<I>
+    where I: IntoIterator,
+          I::Item: IntoFuture,
+{
+    elems: Vec<ElemState<<I::Item as IntoFuture>::Future>>,
+}
+
+impl<I> fmt::Debug for JoinAllResults<I>
+    where I: IntoIterator,
+          I::Item: IntoFuture,
+          <<I as IntoIterator>::Item as IntoFuture>::Future: fmt::Debug,
+          <<I as IntoIterator>::Item as IntoFuture>::Item: fmt::Debug,
+          <<I as IntoIterator>::Item as IntoFuture>::Error: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("JoinAllResults")
+            .field("elems", &self.elems)
+            .finish()
+    }
+}
+
+/// Creates a future which represents a collection of the results of the futures
+/// given, whether they succeeded or failed.
+///
+/// The returned future will drive execution for all of its underlying futures
+/// until every one of them has completed, collecting their results into a
+/// destination `Vec<Result<T, E>>` in the same order as they were provided.
+/// Unlike `join_all`, an error from one future doesn't cancel the others, and
+/// the returned future itself never fails.
+///
+/// # Examples
+///
+/// ```
+/// use futures::future::*;
+///
+/// let f = join_all_results(vec![
+///     ok::<u32, u32>(1),
+///     err::<u32, u32>(2),
+///     ok::<u32, u32>(3),
+/// ]);
+/// assert_eq!(f.wait(), Ok(vec![Ok(1), Err(2), Ok(3)]));
+/// ```
+pub fn join_all_results<I>(i: I) -> JoinAllResults<I>
+    where I: IntoIterator,
+          I::Item: IntoFuture,
+{
+    let elems = i.into_iter().map(|f| {
+        ElemState::Pending(f.into_future())
+    }).collect();
+    JoinAllResults { elems: elems }
+}
+
+impl<I> Future for JoinAllResults<I>
+    where I: IntoIterator,
+          I::Item: IntoFuture,
+{
+    type Item = Vec<Result<<I::Item as IntoFuture>::Item, <I::Item as IntoFuture>::Error>>;
+    type Error = (); // Change this to ! once it stabilizes
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        let mut all_done = true;
+
+        for elem in self.elems.iter_mut() {
+            let done_val = match *elem {
+                ElemState::Pending(ref mut t) => {
+                    match t.poll() {
+                        Ok(Async::Ready(v)) => Ok(v),
+                        Ok(Async::NotReady) => {
+                            all_done = false;
+                            continue
+                        }
+                        Err(e) => Err(e),
+                    }
+                }
+                ElemState::Done(_) => continue,
+            };
+            *elem = ElemState::Done(done_val);
+        }
+
+        if all_done {
+            let elems = mem::replace(&mut self.elems, Vec::new());
+            let result = elems.into_iter().map(|e| {
+                match e {
+                    ElemState::Done(r) => r,
+                    _ => unreachable!(),
+                }
+            }).collect();
+            Ok(Async::Ready(result))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
+}
@@ -97,3 +97,74 @@ impl<S, T, A, F> Future for LoopFn<A, F>
         }
     }
 }
+
+/// A future implementing a tail-recursive loop which threads an accumulator
+/// alongside the loop state.
+///
+/// Created by the `loop_fn_acc` function.
+#[derive(Debug)]
+pub struct LoopFnAcc<A, F> where A: IntoFuture {
+    future: A::Future,
+    func: F,
+}
+
+/// Creates a new future implementing a tail-recursive loop with a separate
+/// accumulator.
+///
+/// This is like `loop_fn`, except that the loop function is called with both
+/// the loop state and an accumulator, starting with `initial_state` and
+/// `initial_acc`. On successful completion, the future it returns should
+/// output a `Loop<(T, Acc), (S, Acc)>`, handing back the accumulator with
+/// either variant.
+///
+/// `Loop::Break((T, Acc))` halts the loop and completes the future with the
+/// final accumulator. The `T` is dropped, so it's usually `()` when the loop
+/// only ever has the accumulator to hand back.
+///
+/// `Loop::Continue((S, Acc))` reinvokes the loop function with state `S` and
+/// the updated accumulator.
+///
+/// # Examples
+///
+/// ```
+/// use futures::future::{ok, loop_fn_acc, Future, Loop};
+///
+/// // Sum up the numbers 1 to 10, counting with the state.
+/// let sum = loop_fn_acc(1, 0, |n, total| {
+///     if n > 10 {
+///         ok::<_, ()>(Loop::Break(((), total)))
+///     } else {
+///         ok(Loop::Continue((n + 1, total + n)))
+///     }
+/// });
+/// assert_eq!(sum.wait(), Ok(55));
+/// ```
+pub fn loop_fn_acc<S, Acc, T, A, F>(initial_state: S, initial_acc: Acc, mut func: F)
+    -> LoopFnAcc<A, F>
+    where F: FnMut(S, Acc) -> A,
+          A: IntoFuture<Item = Loop<(T, Acc), (S, Acc)>>,
+{
+    LoopFnAcc {
+        future: func(initial_state, initial_acc).into_future(),
+        func: func,
+    }
+}
+
+impl<S, Acc, T, A, F> Future for LoopFnAcc<A, F>
+    where F: FnMut(S, Acc) -> A,
+          A: IntoFuture<Item = Loop<(T, Acc), (S, Acc)>>,
+{
+    type Item = Acc;
+    type Error = A::Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        loop {
+            match try_ready!(self.future.poll()) {
+                Loop::Break((_, acc)) => return Ok(Async::Ready(acc)),
+                Loop::Continue((s, acc)) => {
+                    self.future = (self.func)(s, acc).into_future()
+                }
+            }
+        }
+    }
+}
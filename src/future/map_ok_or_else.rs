@@ -0,0 +1,47 @@
+use core::marker::PhantomData;
+
+use {Future, Poll, Async};
+
+/// Future for the `map_ok_or_else` combinator, mapping both the item and the
+/// error of a future to a single item type.
+///
+/// This is created by the `Future::map_ok_or_else` method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct MapOkOrElse<A, FO, FE, E> where A: Future {
+    future: A,
+    f: Option<(FO, FE)>,
+    e: PhantomData<E>,
+}
+
+pub fn new<A, FO, FE, E>(future: A, ok_fn: FO, err_fn: FE) -> MapOkOrElse<A, FO, FE, E>
+    where A: Future,
+{
+    MapOkOrElse {
+        future: future,
+        f: Some((ok_fn, err_fn)),
+        e: PhantomData,
+    }
+}
+
+impl<U, A, FO, FE, E> Future for MapOkOrElse<A, FO, FE, E>
+    where A: Future,
+          FO: FnOnce(A::Item) -> U,
+          FE: FnOnce(A::Error) -> U,
+{
+    type Item = U;
+    type Error = E;
+
+    fn poll(&mut self) -> Poll<U, E> {
+        let res = match self.future.poll() {
+            Ok(Async::NotReady) => return Ok(Async::NotReady),
+            Ok(Async::Ready(e)) => Ok(e),
+            Err(e) => Err(e),
+        };
+        let (ok_fn, err_fn) = self.f.take().expect("cannot poll MapOkOrElse twice");
+        Ok(Async::Ready(match res {
+            Ok(item) => ok_fn(item),
+            Err(e) => err_fn(e),
+        }))
+    }
+}
@@ -10,15 +10,17 @@ use core::result;
 mod empty;
 mod lazy;
 mod poll_fn;
+mod ready_fn;
 #[path = "result.rs"]
 mod result_;
 mod loop_fn;
 mod option;
 pub use self::empty::{empty, Empty};
 pub use self::lazy::{lazy, Lazy};
-pub use self::poll_fn::{poll_fn, PollFn};
-pub use self::result_::{result, ok, err, FutureResult};
-pub use self::loop_fn::{loop_fn, Loop, LoopFn};
+pub use self::poll_fn::{poll_fn, poll_fn_stateful, PollFn, PollFnStateful};
+pub use self::ready_fn::{ready_fn, ReadyFn};
+pub use self::result_::{result, ok, err, from_option, FutureResult};
+pub use self::loop_fn::{loop_fn, loop_fn_acc, Loop, LoopFn, LoopFnAcc};
 
 #[doc(hidden)]
 #[deprecated(since = "0.1.4", note = "use `ok` instead")]
@@ -51,12 +53,17 @@ mod join;
 mod map;
 mod map_err;
 mod from_err;
+mod map_ok_or_else;
+mod drop_output;
+mod to_spawnable;
 mod or_else;
 mod select;
 mod select2;
+mod select_either;
 mod then;
 mod either;
 mod inspect;
+mod inspect_err;
 
 // impl details
 mod chain;
@@ -70,24 +77,33 @@ pub use self::join::{Join, Join3, Join4, Join5};
 pub use self::map::Map;
 pub use self::map_err::MapErr;
 pub use self::from_err::FromErr;
+pub use self::map_ok_or_else::MapOkOrElse;
+pub use self::drop_output::DropOutput;
+pub use self::to_spawnable::ToSpawnable;
 pub use self::or_else::OrElse;
 pub use self::select::{Select, SelectNext};
 pub use self::select2::Select2;
+pub use self::select_either::SelectEither;
 pub use self::then::Then;
 pub use self::either::Either;
 pub use self::inspect::Inspect;
+pub use self::inspect_err::InspectErr;
 
 if_std! {
     mod catch_unwind;
     mod join_all;
+    mod join_all_results;
     mod select_all;
     mod select_ok;
     mod shared;
+    mod timeout_at;
     pub use self::catch_unwind::CatchUnwind;
     pub use self::join_all::{join_all, JoinAll};
+    pub use self::join_all_results::{join_all_results, JoinAllResults};
     pub use self::select_all::{SelectAll, SelectAllNext, select_all};
     pub use self::select_ok::{SelectOk, select_ok};
     pub use self::shared::{Shared, SharedItem, SharedError};
+    pub use self::timeout_at::{TimeoutAt, TimeoutError};
 
     #[doc(hidden)]
     #[deprecated(since = "0.1.4", note = "use join_all instead")]
@@ -105,6 +121,11 @@ if_std! {
                          details in https://github.com/alexcrichton/futures-rs/issues/228")]
     pub type BoxFuture<T, E> = ::std::boxed::Box<Future<Item = T, Error = E> + Send>;
 
+    /// A type alias for `Box<Future>`, without a `Send` bound.
+    ///
+    /// This is the type returned by `Future::boxed_local`.
+    pub type LocalBoxFuture<T, E> = ::std::boxed::Box<Future<Item = T, Error = E>>;
+
     impl<F: ?Sized + Future> Future for ::std::boxed::Box<F> {
         type Item = F::Item;
         type Error = F::Error;
@@ -331,6 +352,35 @@ pub trait Future {
         ::std::boxed::Box::new(self)
     }
 
+    /// Convenience function for turning this future into a trait object
+    /// which isn't required to be `Send`.
+    ///
+    /// This simply avoids the need to write `Box::new` and can often help with
+    /// type inference as well by always returning a trait object. Unlike
+    /// `Send` trait objects this can hold futures which must stay on the
+    /// thread which created them, for example because they contain an `Rc`.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    ///
+    /// use futures::prelude::*;
+    /// use futures::future::{self, LocalBoxFuture};
+    ///
+    /// let shared = Rc::new(1);
+    /// let a: LocalBoxFuture<Rc<i32>, ()> = future::ok(shared).boxed_local();
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn boxed_local(self) -> LocalBoxFuture<Self::Item, Self::Error>
+        where Self: Sized + 'static
+    {
+        ::std::boxed::Box::new(self)
+    }
+
     /// Map this future's result to a different type, returning a new future of
     /// the resulting type.
     ///
@@ -443,6 +493,83 @@ pub trait Future {
         assert_future::<Self::Item, E, _>(from_err::new(self))
     }
 
+    /// Map both the item and the error of this future to a single item type,
+    /// returning a new future which can't fail.
+    ///
+    /// This is the futures equivalent of `Result::map_or_else`: when this
+    /// future resolves, `ok_fn` is called with its item, and if it fails,
+    /// `err_fn` is called with its error instead. Either way the returned
+    /// future resolves with the closure's result. This is a shorthand for
+    /// `then(|res| Ok(match res { ... }))`, and can be used to handle errors
+    /// before spawning a future.
+    ///
+    /// The returned future never fails, so its error type `E` is picked by
+    /// the caller, usually through inference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::future;
+    ///
+    /// let future = future::err::<u32, &str>("boom")
+    ///     .map_ok_or_else(|x| x.to_string(), |e| format!("failed: {}", e));
+    /// assert_eq!(future.wait(), Ok::<_, ()>("failed: boom".to_string()));
+    /// ```
+    fn map_ok_or_else<U, FO, FE, E>(self, ok_fn: FO, err_fn: FE)
+        -> MapOkOrElse<Self, FO, FE, E>
+        where FO: FnOnce(Self::Item) -> U,
+              FE: FnOnce(Self::Error) -> U,
+              Self: Sized,
+    {
+        assert_future::<U, E, _>(map_ok_or_else::new(self, ok_fn, err_fn))
+    }
+
+    /// Discard the item of this future, resolving to `()` instead.
+    ///
+    /// This is a shorthand for `map(|_| ())`. Errors are passed through
+    /// unchanged, so this may still need to be combined with `map_err` when a
+    /// future with both `Item = ()` and `Error = ()` is required, such as for
+    /// spawning. The `to_spawnable` method does both at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::future;
+    ///
+    /// let future = future::ok::<u32, u32>(1).drop_output();
+    /// assert_eq!(future.wait(), Ok(()));
+    /// ```
+    fn drop_output(self) -> DropOutput<Self>
+        where Self: Sized,
+    {
+        assert_future::<(), Self::Error, _>(drop_output::new(self))
+    }
+
+    /// Discard both the item and the error of this future, resolving to `()`
+    /// either way.
+    ///
+    /// This is a shorthand for `map(|_| ()).map_err(|_| ())`, producing a
+    /// future with `Item = ()` and `Error = ()` as commonly required for
+    /// spawning it on an executor. Note that the error is silently dropped, so
+    /// it should be handled beforehand if it matters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::future;
+    ///
+    /// let future = future::err::<u32, u32>(1).to_spawnable();
+    /// assert_eq!(future.wait(), Err(()));
+    /// ```
+    fn to_spawnable(self) -> ToSpawnable<Self>
+        where Self: Sized,
+    {
+        assert_future::<(), (), _>(to_spawnable::new(self))
+    }
+
     /// Chain on a computation for when a future finished, passing the result of
     /// the future to the provided closure `f`.
     ///
@@ -673,6 +800,34 @@ pub trait Future {
         select2::new(self, other.into_future())
     }
 
+    /// Waits for either one of two differently-typed futures to complete,
+    /// dropping the other one.
+    ///
+    /// This is like `select2`, except that the future which didn't complete
+    /// first isn't handed back. The returned future resolves to `Either::A`
+    /// with this future's value if it completed first, or `Either::B` with
+    /// the value of `other` otherwise. Errors are tagged with the side they
+    /// came from in the same way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::future::{self, Either};
+    ///
+    /// let never = future::empty::<u32, ()>();
+    /// let now = future::ok::<_, ()>("now");
+    /// match never.select_either(now).wait() {
+    ///     Ok(Either::B(value)) => assert_eq!(value, "now"),
+    ///     _ => panic!("the immediate future should win"),
+    /// }
+    /// ```
+    fn select_either<B>(self, other: B) -> SelectEither<Self, B::Future>
+        where B: IntoFuture, Self: Sized
+    {
+        select_either::new(self, other.into_future())
+    }
+
     /// Joins the result of two futures, waiting for them both to complete.
     ///
     /// This function will return a new future which awaits both this and the
@@ -753,6 +908,45 @@ pub trait Future {
                    e.into_future())
     }
 
+    /// Wraps this future in the `A` variant of an `Either`.
+    ///
+    /// This is a shorthand for `Either::A(future)`, useful when the branches
+    /// of an `if` or `match` return different types of futures with the same
+    /// item and error types, which need to be unified into a single type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::future;
+    ///
+    /// let x = 6;
+    /// let future = if x < 10 {
+    ///     future::ok::<_, bool>(x).left_future()
+    /// } else {
+    ///     future::empty().right_future()
+    /// };
+    ///
+    /// assert_eq!(future.wait(), Ok(x));
+    /// ```
+    fn left_future<B>(self) -> Either<Self, B>
+        where B: Future<Item = Self::Item, Error = Self::Error>,
+              Self: Sized
+    {
+        Either::A(self)
+    }
+
+    /// Wraps this future in the `B` variant of an `Either`.
+    ///
+    /// This is a shorthand for `Either::B(future)`, see `left_future` for
+    /// more details.
+    fn right_future<A>(self) -> Either<A, Self>
+        where A: Future<Item = Self::Item, Error = Self::Error>,
+              Self: Sized
+    {
+        Either::B(self)
+    }
+
     /// Convert this future into a single element stream.
     ///
     /// The returned stream contains single success if this future resolves to
@@ -835,6 +1029,11 @@ pub trait Future {
     /// convenient to work with that stream as if stream was available at the
     /// call site.
     ///
+    /// If this future resolves to an error then that error is yielded as the
+    /// first and only item of the returned stream, after which the stream
+    /// ends. This requires the error type of the future and the stream to be
+    /// the same; see `flatten_stream_err` for when they differ.
+    ///
     /// Note that this function consumes this future and returns a wrapped
     /// version of it.
     ///
@@ -863,6 +1062,41 @@ pub trait Future {
         flatten_stream::new(self)
     }
 
+    /// Flatten the execution of this future when the successful result of this
+    /// future is a stream, converting this future's error with `f`.
+    ///
+    /// This is the same as `flatten_stream`, except that the error type of this
+    /// future doesn't have to match the error type of the stream it resolves
+    /// to. If this future resolves to an error then it is passed to `f` and
+    /// the result is yielded as the first and only item of the returned
+    /// stream, after which the stream ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::future;
+    /// use futures::stream;
+    ///
+    /// type Lines = stream::IterOk<std::vec::IntoIter<String>, String>;
+    /// let future_of_a_stream = future::err::<Lines, u16>(404);
+    ///
+    /// let stream = future_of_a_stream.flatten_stream_err(|code| {
+    ///     format!("request failed: {}", code)
+    /// });
+    ///
+    /// let mut iter = stream.wait();
+    /// assert_eq!(Err("request failed: 404".to_string()), iter.next().unwrap());
+    /// assert_eq!(None, iter.next());
+    /// ```
+    fn flatten_stream_err<F>(self, f: F) -> FlattenStream<MapErr<Self, F>>
+        where <Self as Future>::Item: stream::Stream,
+              F: FnOnce(Self::Error) -> <Self::Item as stream::Stream>::Error,
+              Self: Sized
+    {
+        flatten_stream::new(map_err::new(self, f))
+    }
+
     /// Fuse a future such that `poll` will never again be called once it has
     /// completed.
     ///
@@ -926,6 +1160,31 @@ pub trait Future {
         assert_future::<Self::Item, Self::Error, _>(inspect::new(self, f))
     }
 
+    /// Do something with the error of a future, passing it on.
+    ///
+    /// This is the counterpart of `inspect` for the error path: `f` is called
+    /// with a reference to the error if this future fails, and the error is
+    /// then passed on unchanged. If this future succeeds, `f` is never
+    /// called. This is useful for logging or counting errors without handling
+    /// them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::future;
+    ///
+    /// let future = future::err::<u32, u32>(1);
+    /// let new_future = future.inspect_err(|&e| println!("about to fail: {}", e));
+    /// assert_eq!(new_future.wait(), Err(1));
+    /// ```
+    fn inspect_err<F>(self, f: F) -> InspectErr<Self, F>
+        where F: FnOnce(&Self::Error),
+              Self: Sized,
+    {
+        assert_future::<Self::Item, Self::Error, _>(inspect_err::new(self, f))
+    }
+
     /// Catches unwinding panics while polling the future.
     ///
     /// In general, panics within a future can propagate all the way out to the
@@ -939,6 +1198,26 @@ pub trait Future {
     /// after-the fact. To assist using this method, the `Future` trait is also
     /// implemented for `AssertUnwindSafe<F>` where `F` implements `Future`.
     ///
+    /// The returned future never unwinds. A panic while polling resolves it to
+    /// an error holding the panic payload, and otherwise it resolves to the
+    /// `Result` of this future. If the panic should instead travel alongside
+    /// the item, with this future's error left in the error channel, that
+    /// shape can be recovered with `then`:
+    ///
+    /// ```rust
+    /// use futures::prelude::*;
+    /// use futures::future;
+    ///
+    /// let future = future::err::<i32, u32>(1)
+    ///     .catch_unwind()
+    ///     .then(|res| match res {
+    ///         Ok(Ok(item)) => Ok(Ok(item)),
+    ///         Ok(Err(e)) => Err(e),
+    ///         Err(panic) => Ok(Err(panic)),
+    ///     });
+    /// assert_eq!(future.wait().unwrap_err(), 1);
+    /// ```
+    ///
     /// This method is only available when the `use_std` feature of this
     /// library is activated, and it is activated by default.
     ///
@@ -1012,6 +1291,25 @@ pub trait Future {
     {
         shared::new(self)
     }
+
+    /// Fails this future with `TimeoutError::Elapsed` if it hasn't completed
+    /// by the `deadline`.
+    ///
+    /// The `timer` is used to wait for the deadline, and only once this future
+    /// isn't ready yet is the deadline checked. Errors of this future are
+    /// wrapped in `TimeoutError::Inner`, and if the timer fails then
+    /// `TimeoutError::Timer` is returned. This future is dropped along with
+    /// the returned future.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    #[cfg(feature = "use_std")]
+    fn timeout_at<T>(self, timer: &T, deadline: ::std::time::Instant) -> TimeoutAt<Self, T::Delay>
+        where T: ::timer::Timer,
+              Self: Sized
+    {
+        timeout_at::new(self, timer, deadline)
+    }
 }
 
 impl<'a, F: ?Sized + Future> Future for &'a mut F {
@@ -1159,12 +1457,31 @@ impl<F> ExecuteError<F> {
     }
 }
 
-impl<F> fmt::Debug for ExecuteError<F> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl<F> ExecuteError<F> {
+    fn message(&self) -> &'static str {
         match self.kind {
-            ExecuteErrorKind::Shutdown => "executor has shut down".fmt(f),
-            ExecuteErrorKind::NoCapacity => "executor has no more capacity".fmt(f),
+            ExecuteErrorKind::Shutdown => "executor has shut down",
+            ExecuteErrorKind::NoCapacity => "executor has no more capacity",
             ExecuteErrorKind::__Nonexhaustive => panic!(),
         }
     }
 }
+
+impl<F> fmt::Debug for ExecuteError<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.message(), f)
+    }
+}
+
+impl<F> fmt::Display for ExecuteError<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<F: ::std::any::Any> ::std::error::Error for ExecuteError<F> {
+    fn description(&self) -> &str {
+        self.message()
+    }
+}
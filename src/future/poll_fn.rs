@@ -1,6 +1,6 @@
 //! Definition of the `PollFn` adapter combinator
 
-use {Future, Poll};
+use {Future, Poll, Async};
 
 /// A future which adapts a function returning `Poll`.
 ///
@@ -15,6 +15,13 @@ pub struct PollFn<F> {
 ///
 /// Polling the returned future delegates to the wrapped function.
 ///
+/// The function is called from within the task polling the future, so it
+/// can use `task::current` just like a hand-written `Future::poll` would.
+/// Before returning `NotReady` it must make sure the task will be notified
+/// once it can make progress, for example by handing the `Task` to a
+/// callback, or by calling `task::current().notify()` to be polled again
+/// right away.
+///
 /// # Examples
 ///
 /// ```
@@ -43,3 +50,60 @@ impl<T, E, F> Future for PollFn<F>
         (self.inner)()
     }
 }
+
+/// A future which adapts a function returning `Poll` and threads a piece of
+/// owned state through each call.
+///
+/// Created by the `poll_fn_stateful` function.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct PollFnStateful<S, F> {
+    state: Option<S>,
+    inner: F,
+}
+
+/// Creates a new future wrapping around a function returning `Poll` which is
+/// given mutable access to `state` each time it's called.
+///
+/// This is useful for small hand-written state machines where the state isn't
+/// `Copy` and would otherwise be awkward to capture in a `poll_fn` closure.
+/// The state is dropped as soon as the function returns `Ready` or an error,
+/// and the returned future will panic if it's polled again after that.
+///
+/// # Examples
+///
+/// ```
+/// use futures::future::poll_fn_stateful;
+/// use futures::{Async, Future, Poll};
+///
+/// let lines = vec!["Hello", "World"];
+/// let read_future = poll_fn_stateful(lines.into_iter(), |lines| {
+///     let res: Poll<Option<&str>, ()> = Ok(Async::Ready(lines.next()));
+///     res
+/// });
+/// assert_eq!(read_future.wait(), Ok(Some("Hello")));
+/// ```
+pub fn poll_fn_stateful<S, T, E, F>(state: S, f: F) -> PollFnStateful<S, F>
+    where F: FnMut(&mut S) -> ::Poll<T, E>
+{
+    PollFnStateful { state: Some(state), inner: f }
+}
+
+impl<S, T, E, F> Future for PollFnStateful<S, F>
+    where F: FnMut(&mut S) -> Poll<T, E>
+{
+    type Item = T;
+    type Error = E;
+
+    fn poll(&mut self) -> Poll<T, E> {
+        let res = {
+            let state = self.state.as_mut().expect("cannot poll PollFnStateful twice");
+            (self.inner)(state)
+        };
+        match res {
+            Ok(Async::NotReady) => {}
+            _ => self.state = None,
+        }
+        res
+    }
+}
@@ -0,0 +1,48 @@
+//! Definition of the `ReadyFn` future, an immediately ready future which
+//! produces its result with a closure on every poll.
+
+use {Future, Poll, Async};
+
+/// A future which is immediately ready with the result of a closure, calling
+/// the closure again each time it's polled.
+///
+/// This is created by the `ready_fn` function.
+#[derive(Debug, Clone)]
+#[must_use = "futures do nothing unless polled"]
+pub struct ReadyFn<F> {
+    f: F,
+}
+
+/// Creates a new future which is immediately ready with the result of `f`.
+///
+/// Unlike `result`, which hands out its value once and panics if polled again,
+/// this future calls `f` every time it's polled, so it can be polled any
+/// number of times and produces a fresh result each time. If `f` is `Clone`
+/// the future is as well, which makes it handy for building a fresh attempt
+/// of a fallible operation, for example in a `loop_fn` which retries it.
+///
+/// # Examples
+///
+/// ```
+/// use futures::future::*;
+///
+/// let attempt = ready_fn(|| "42".parse::<u32>());
+/// assert_eq!(attempt.clone().wait(), Ok(42));
+/// assert_eq!(attempt.wait(), Ok(42));
+/// ```
+pub fn ready_fn<F, T, E>(f: F) -> ReadyFn<F>
+    where F: Fn() -> Result<T, E>,
+{
+    ReadyFn { f: f }
+}
+
+impl<F, T, E> Future for ReadyFn<F>
+    where F: Fn() -> Result<T, E>,
+{
+    type Item = T;
+    type Error = E;
+
+    fn poll(&mut self) -> Poll<T, E> {
+        (self.f)().map(Async::Ready)
+    }
+}
@@ -65,6 +65,31 @@ pub fn err<T, E>(e: E) -> FutureResult<T, E> {
     result(Err(e))
 }
 
+/// Creates a "leaf future" from an optional value, which is successful with
+/// the value if there is one, and fails with the error returned by `err`
+/// otherwise.
+///
+/// This is a shorthand for `result(opt.ok_or_else(err))`, avoiding the need
+/// for an `Either` to unify an `ok` and an `err` future.
+///
+/// # Examples
+///
+/// ```
+/// use futures::prelude::*;
+/// use futures::future::*;
+///
+/// let found = from_option(Some(1), || "missing");
+/// assert_eq!(found.wait(), Ok(1));
+///
+/// let missing = from_option(None::<u32>, || "missing");
+/// assert_eq!(missing.wait(), Err("missing"));
+/// ```
+pub fn from_option<T, E, F>(opt: Option<T>, err: F) -> FutureResult<T, E>
+    where F: FnOnce() -> E,
+{
+    result(opt.ok_or_else(err))
+}
+
 impl<T, E> Future for FutureResult<T, E> {
     type Item = T;
     type Error = E;
@@ -0,0 +1,37 @@
+use {Future, Poll, Async};
+use future::Either;
+
+/// Future for the `select_either` combinator, waiting for one of two
+/// differently-typed futures to complete and dropping the other one.
+///
+/// This is created by the `Future::select_either` method.
+#[must_use = "futures do nothing unless polled"]
+#[derive(Debug)]
+pub struct SelectEither<A, B> {
+    inner: Option<(A, B)>,
+}
+
+pub fn new<A, B>(a: A, b: B) -> SelectEither<A, B> {
+    SelectEither { inner: Some((a, b)) }
+}
+
+impl<A, B> Future for SelectEither<A, B> where A: Future, B: Future {
+    type Item = Either<A::Item, B::Item>;
+    type Error = Either<A::Error, B::Error>;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        let (mut a, mut b) = self.inner.take().expect("cannot poll SelectEither twice");
+        match a.poll() {
+            Err(e) => Err(Either::A(e)),
+            Ok(Async::Ready(x)) => Ok(Async::Ready(Either::A(x))),
+            Ok(Async::NotReady) => match b.poll() {
+                Err(e) => Err(Either::B(e)),
+                Ok(Async::Ready(x)) => Ok(Async::Ready(Either::B(x))),
+                Ok(Async::NotReady) => {
+                    self.inner = Some((a, b));
+                    Ok(Async::NotReady)
+                }
+            }
+        }
+    }
+}
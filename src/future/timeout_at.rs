@@ -0,0 +1,80 @@
+use std::error::Error;
+use std::fmt;
+use std::time::Instant;
+
+use {Future, Poll, Async};
+use timer::Timer;
+
+/// Future for the `Future::timeout_at` combinator, failing if the underlying
+/// future doesn't complete before a deadline.
+///
+/// This is created by the `Future::timeout_at` method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct TimeoutAt<A, D> {
+    future: A,
+    delay: D,
+}
+
+/// The error of a `TimeoutAt` future.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeoutError<E> {
+    /// The deadline was reached before the future completed.
+    Elapsed,
+    /// The future failed with this error before the deadline.
+    Inner(E),
+    /// The timer failed, so the deadline can no longer be tracked.
+    Timer,
+}
+
+pub fn new<A, T>(future: A, timer: &T, deadline: Instant) -> TimeoutAt<A, T::Delay>
+    where A: Future,
+          T: Timer,
+{
+    TimeoutAt {
+        future: future,
+        delay: timer.delay(deadline),
+    }
+}
+
+impl<A, D> Future for TimeoutAt<A, D>
+    where A: Future,
+          D: Future<Item = (), Error = ()>,
+{
+    type Item = A::Item;
+    type Error = TimeoutError<A::Error>;
+
+    fn poll(&mut self) -> Poll<A::Item, TimeoutError<A::Error>> {
+        // The future gets a chance to complete even if the deadline has
+        // passed by the time it's first polled.
+        match self.future.poll() {
+            Ok(Async::NotReady) => {}
+            other => return other.map_err(TimeoutError::Inner),
+        }
+        match self.delay.poll() {
+            Ok(Async::Ready(())) => Err(TimeoutError::Elapsed),
+            Ok(Async::NotReady) => Ok(Async::NotReady),
+            Err(()) => Err(TimeoutError::Timer),
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for TimeoutError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TimeoutError::Elapsed => f.write_str("deadline has elapsed"),
+            TimeoutError::Inner(ref e) => e.fmt(f),
+            TimeoutError::Timer => f.write_str("timer has failed"),
+        }
+    }
+}
+
+impl<E: Error> Error for TimeoutError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            TimeoutError::Elapsed => "deadline has elapsed",
+            TimeoutError::Inner(_) => "future failed before the deadline",
+            TimeoutError::Timer => "timer has failed",
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use {Future, Poll, Async};
+
+/// Future for the `to_spawnable` combinator, discarding both the item and the
+/// error of a future.
+///
+/// This is created by the `Future::to_spawnable` method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct ToSpawnable<A> where A: Future {
+    future: A,
+}
+
+pub fn new<A>(future: A) -> ToSpawnable<A>
+    where A: Future
+{
+    ToSpawnable {
+        future: future,
+    }
+}
+
+impl<A: Future> Future for ToSpawnable<A> {
+    type Item = ();
+    type Error = ();
+
+    fn poll(&mut self) -> Poll<(), ()> {
+        match self.future.poll() {
+            Ok(Async::Ready(_)) => Ok(Async::Ready(())),
+            Ok(Async::NotReady) => Ok(Async::NotReady),
+            Err(_) => Err(()),
+        }
+    }
+}
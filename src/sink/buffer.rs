@@ -108,4 +108,19 @@ impl<S: Sink> Sink for Buffer<S> {
         assert_eq!(self.buf.len(), 0);
         self.sink.close()
     }
+
+    fn poll_ready(&mut self) -> Poll<(), Self::SinkError> {
+        if self.cap == 0 {
+            return self.sink.poll_ready();
+        }
+
+        if self.buf.len() == self.cap {
+            self.try_empty_buffer()?;
+        }
+        if self.buf.len() == self.cap {
+            Ok(Async::NotReady)
+        } else {
+            Ok(Async::Ready(()))
+        }
+    }
 }
@@ -0,0 +1,63 @@
+use core::marker;
+
+use {Poll, Async, StartSend, AsyncSink};
+use sink::Sink;
+
+/// A sink which accepts every item and discards it.
+///
+/// This sink can be created with the `sink::drain` function.
+#[derive(Debug)]
+#[must_use = "sinks do nothing unless used"]
+pub struct Drain<T, E> {
+    items_sent: u64,
+    _data: marker::PhantomData<(T, E)>,
+}
+
+/// Creates a sink which accepts and discards every item sent to it.
+///
+/// The returned sink is always ready to accept another item, and flushing or
+/// closing it always succeeds immediately. This is the sink counterpart to
+/// `stream::empty`, useful as an endpoint for `Stream::forward` when only the
+/// side effects of a stream matter.
+///
+/// # Examples
+///
+/// ```
+/// use futures::prelude::*;
+/// use futures::{sink, stream};
+///
+/// let items = stream::iter_ok::<_, ()>(vec![1, 2, 3]);
+/// let (_, drain) = items.forward(sink::drain()).wait().unwrap();
+/// assert_eq!(drain.items_sent(), 3);
+/// ```
+pub fn drain<T, E>() -> Drain<T, E> {
+    Drain {
+        items_sent: 0,
+        _data: marker::PhantomData,
+    }
+}
+
+impl<T, E> Drain<T, E> {
+    /// Returns the number of items which have been sent to this sink.
+    pub fn items_sent(&self) -> u64 {
+        self.items_sent
+    }
+}
+
+impl<T, E> Sink for Drain<T, E> {
+    type SinkItem = T;
+    type SinkError = E;
+
+    fn start_send(&mut self, _item: T) -> StartSend<T, E> {
+        self.items_sent += 1;
+        Ok(AsyncSink::Ready)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), E> {
+        Ok(Async::Ready(()))
+    }
+
+    fn close(&mut self) -> Poll<(), E> {
+        Ok(Async::Ready(()))
+    }
+}
@@ -87,6 +87,20 @@ impl<A, B> Sink for Fanout<A, B>
             Ok(Async::NotReady)
         } 
     }
+
+    fn poll_ready(&mut self) -> Poll<(), Self::SinkError> {
+        self.left.keep_flushing()?;
+        self.right.keep_flushing()?;
+        // Only if both downstream sinks can take the next item, signal
+        // readiness.
+        let left_ready = self.left.is_ready() && self.left.sink.poll_ready()?.is_ready();
+        let right_ready = self.right.is_ready() && self.right.sink.poll_ready()?.is_ready();
+        if left_ready && right_ready {
+            Ok(Async::Ready(()))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -59,6 +59,10 @@ impl<S, E> Sink for SinkFromErr<S, E>
     fn close(&mut self) -> Poll<(), Self::SinkError> {
         self.sink.close().map_err(|e| e.into())
     }
+
+    fn poll_ready(&mut self) -> Poll<(), Self::SinkError> {
+        self.sink.poll_ready().map_err(|e| e.into())
+    }
 }
 
 impl<S: ::stream::Stream, E> ::stream::Stream for SinkFromErr<S, E> {
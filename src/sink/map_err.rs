@@ -52,6 +52,10 @@ impl<S, F, E> Sink for SinkMapErr<S, F>
     fn close(&mut self) -> Poll<(), Self::SinkError> {
         self.sink.close().map_err(|e| self.f.take().expect("cannot use MapErr after an error")(e))
     }
+
+    fn poll_ready(&mut self) -> Poll<(), Self::SinkError> {
+        self.sink.poll_ready().map_err(|e| self.f.take().expect("cannot use MapErr after an error")(e))
+    }
 }
 
 impl<S: Stream, F> Stream for SinkMapErr<S, F> {
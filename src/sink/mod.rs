@@ -12,6 +12,7 @@ use {IntoFuture, Poll, StartSend};
 use stream::Stream;
 
 mod with;
+mod drain;
 mod with_flat_map;
 // mod with_map;
 // mod with_filter;
@@ -20,6 +21,7 @@ mod flush;
 mod from_err;
 mod send;
 mod send_all;
+mod send_all_batched;
 mod map_err;
 mod fanout;
 
@@ -71,14 +73,20 @@ if_std! {
         fn close(&mut self) -> Poll<(), Self::SinkError> {
             (**self).close()
         }
+
+        fn poll_ready(&mut self) -> Poll<(), Self::SinkError> {
+            (**self).poll_ready()
+        }
     }
 }
 
 pub use self::with::With;
+pub use self::drain::{drain, Drain};
 pub use self::with_flat_map::WithFlatMap;
 pub use self::flush::Flush;
 pub use self::send::Send;
 pub use self::send_all::SendAll;
+pub use self::send_all_batched::SendAllBatched;
 pub use self::map_err::SinkMapErr;
 pub use self::from_err::SinkFromErr;
 pub use self::fanout::Fanout;
@@ -286,6 +294,33 @@ pub trait Sink {
     #[cfg(not(feature = "with-deprecated"))]
     fn close(&mut self) -> Poll<(), Self::SinkError>;
 
+    /// Checks whether this sink is ready to accept another item, without
+    /// having to attempt to send one.
+    ///
+    /// Returning `Ready` means that the next call to `start_send` isn't
+    /// expected to hand its item back as `AsyncSink::NotReady`, although it
+    /// may still fail with an error. If `NotReady` is returned then the
+    /// current task is scheduled to receive a notification once the sink may
+    /// be ready again. This allows waiting on the readiness of several sinks
+    /// at once without having to set items aside for the ones which turn out
+    /// not to be ready.
+    ///
+    /// # Compatibility notes
+    ///
+    /// Not every sink is able to tell whether it's ready ahead of time, so by
+    /// default this method always returns `Ready`, leaving `start_send` to
+    /// find out. Sinks which can cheaply tell, such as the bounded `mpsc`
+    /// senders and the `Buffer` adapter, override it to report their actual
+    /// capacity. Adapters wrapping another sink forward it to that sink, so
+    /// they have to override it as well.
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if it's called outside of the context of a task.
+    fn poll_ready(&mut self) -> Poll<(), Self::SinkError> {
+        Ok(::Async::Ready(()))
+    }
+
     /// Creates a new object which will produce a synchronous sink.
     ///
     /// The sink returned does **not** implement the `Sink` trait, and instead
@@ -468,6 +503,30 @@ pub trait Sink {
     {
         send_all::new(self, stream)
     }
+
+    /// A future that completes after the given stream has been fully processed
+    /// into the sink, flushing the sink after every `max_batch` items.
+    ///
+    /// This is like `send_all`, which only flushes the sink whenever the stream
+    /// isn't ready to produce another item, except that this also flushes the
+    /// sink once `max_batch` items have been sent to it since the last flush.
+    /// This puts a bound on how long items may sit unflushed in the sink while
+    /// the stream keeps producing them, while still grouping items together to
+    /// avoid flushing after each one. The sink is closed once the stream is
+    /// exhausted, which flushes any remaining items.
+    ///
+    /// On completion, the pair `(sink, source)` is returned.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `max_batch` is zero.
+    fn send_all_batched<S>(self, stream: S, max_batch: usize) -> SendAllBatched<Self, S>
+        where S: Stream<Item = Self::SinkItem>,
+              Self::SinkError: From<S::Error>,
+              Self: Sized
+    {
+        send_all_batched::new(self, stream, max_batch)
+    }
 }
 
 impl<'a, S: ?Sized + Sink> Sink for &'a mut S {
@@ -486,4 +545,8 @@ impl<'a, S: ?Sized + Sink> Sink for &'a mut S {
     fn close(&mut self) -> Poll<(), Self::SinkError> {
         (**self).close()
     }
+
+    fn poll_ready(&mut self) -> Poll<(), Self::SinkError> {
+        (**self).poll_ready()
+    }
 }
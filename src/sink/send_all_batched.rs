@@ -0,0 +1,109 @@
+use {Poll, Async, Future, AsyncSink};
+use stream::{Stream, Fuse};
+use sink::Sink;
+
+/// Future for the `Sink::send_all_batched` combinator, which sends a stream of
+/// values to a sink, flushing after every batch of values, and then waits
+/// until the sink has fully flushed those values.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct SendAllBatched<T, U: Stream> {
+    sink: Option<T>,
+    stream: Option<Fuse<U>>,
+    buffered: Option<U::Item>,
+    unflushed: usize,
+    max_batch: usize,
+}
+
+pub fn new<T, U>(sink: T, stream: U, max_batch: usize) -> SendAllBatched<T, U>
+    where T: Sink,
+          U: Stream<Item = T::SinkItem>,
+          T::SinkError: From<U::Error>,
+{
+    assert!(max_batch > 0);
+
+    SendAllBatched {
+        sink: Some(sink),
+        stream: Some(stream.fuse()),
+        buffered: None,
+        unflushed: 0,
+        max_batch: max_batch,
+    }
+}
+
+impl<T, U> SendAllBatched<T, U>
+    where T: Sink,
+          U: Stream<Item = T::SinkItem>,
+          T::SinkError: From<U::Error>,
+{
+    fn sink_mut(&mut self) -> &mut T {
+        self.sink.as_mut()
+            .expect("Attempted to poll SendAllBatched after completion")
+    }
+
+    fn stream_mut(&mut self) -> &mut Fuse<U> {
+        self.stream.as_mut()
+            .expect("Attempted to poll SendAllBatched after completion")
+    }
+
+    fn take_result(&mut self) -> (T, U) {
+        let sink = self.sink.take()
+            .expect("Attempted to poll SendAllBatched after completion");
+        let fuse = self.stream.take()
+            .expect("Attempted to poll SendAllBatched after completion");
+        (sink, fuse.into_inner())
+    }
+
+    fn try_start_send(&mut self, item: U::Item) -> Poll<(), T::SinkError> {
+        debug_assert!(self.buffered.is_none());
+        if let AsyncSink::NotReady(item) = self.sink_mut().start_send(item)? {
+            self.buffered = Some(item);
+            return Ok(Async::NotReady)
+        }
+        self.unflushed += 1;
+        Ok(Async::Ready(()))
+    }
+
+    fn flush(&mut self) -> Poll<(), T::SinkError> {
+        try_ready!(self.sink_mut().poll_complete());
+        self.unflushed = 0;
+        Ok(Async::Ready(()))
+    }
+}
+
+impl<T, U> Future for SendAllBatched<T, U>
+    where T: Sink,
+          U: Stream<Item = T::SinkItem>,
+          T::SinkError: From<U::Error>,
+{
+    type Item = (T, U);
+    type Error = T::SinkError;
+
+    fn poll(&mut self) -> Poll<(T, U), T::SinkError> {
+        // If we've got an item buffered already, we need to write it to the
+        // sink before we can do anything else
+        if let Some(item) = self.buffered.take() {
+            try_ready!(self.try_start_send(item))
+        }
+
+        loop {
+            if self.unflushed >= self.max_batch {
+                try_ready!(self.flush());
+            }
+
+            match self.stream_mut().poll()? {
+                Async::Ready(Some(item)) => try_ready!(self.try_start_send(item)),
+                Async::Ready(None) => {
+                    try_ready!(self.sink_mut().close());
+                    return Ok(Async::Ready(self.take_result()))
+                }
+                Async::NotReady => {
+                    if self.unflushed > 0 {
+                        try_ready!(self.flush());
+                    }
+                    return Ok(Async::NotReady)
+                }
+            }
+        }
+    }
+}
@@ -150,4 +150,9 @@ impl<S, U, F, Fut> Sink for With<S, U, F, Fut>
         try_ready!(self.poll());
         Ok(self.sink.close()?)
     }
+
+    fn poll_ready(&mut self) -> Poll<(), Fut::Error> {
+        try_ready!(self.poll());
+        Ok(self.sink.poll_ready()?)
+    }
 }
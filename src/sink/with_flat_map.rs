@@ -123,4 +123,10 @@ where
         assert!(self.stream.is_none());
         self.sink.close()
     }
+    fn poll_ready(&mut self) -> Poll<(), Self::SinkError> {
+        if self.try_empty_stream()?.is_not_ready() {
+            return Ok(Async::NotReady);
+        }
+        self.sink.poll_ready()
+    }
 }
@@ -72,6 +72,10 @@ impl<S, F, U: IntoFuture> ::sink::Sink for AndThen<S, F, U>
     fn close(&mut self) -> Poll<(), S::SinkError> {
         self.stream.close()
     }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
 }
 
 impl<S, F, U> Stream for AndThen<S, F, U>
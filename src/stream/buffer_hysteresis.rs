@@ -0,0 +1,122 @@
+use std::collections::VecDeque;
+
+use {Async, Poll};
+use stream::{Stream, Fuse};
+
+/// An adaptor which reads ahead of its consumer into a buffer, pausing and
+/// resuming between two watermarks.
+///
+/// This is created by the `Stream::buffer_hysteresis` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct BufferHysteresis<S>
+    where S: Stream
+{
+    stream: Fuse<S>,
+    buffer: VecDeque<S::Item>,
+    high: usize,
+    low: usize,
+    paused: bool,
+}
+
+pub fn new<S>(s: S, high: usize, low: usize) -> BufferHysteresis<S>
+    where S: Stream,
+{
+    assert!(low < high, "the low watermark must be below the high watermark");
+
+    BufferHysteresis {
+        stream: super::fuse::new(s),
+        buffer: VecDeque::with_capacity(high),
+        high: high,
+        low: low,
+        paused: false,
+    }
+}
+
+impl<S: Stream> BufferHysteresis<S> {
+    /// Returns whether the underlying stream is currently not being read
+    /// from, because the buffer filled up to the high watermark and hasn't
+    /// drained to the low watermark since.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &S {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut S {
+        self.stream.get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S> ::sink::Sink for BufferHysteresis<S>
+    where S: ::sink::Sink + Stream
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem) -> ::StartSend<S::SinkItem, S::SinkError> {
+        self.stream.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
+}
+
+impl<S> Stream for BufferHysteresis<S>
+    where S: Stream
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<S::Item>, S::Error> {
+        if !self.paused {
+            while self.buffer.len() < self.high {
+                match self.stream.poll()? {
+                    Async::Ready(Some(item)) => self.buffer.push_back(item),
+                    Async::Ready(None) | Async::NotReady => break,
+                }
+            }
+            if self.buffer.len() >= self.high {
+                self.paused = true;
+            }
+        }
+
+        match self.buffer.pop_front() {
+            Some(item) => {
+                if self.paused && self.buffer.len() <= self.low {
+                    self.paused = false;
+                }
+                Ok(Async::Ready(Some(item)))
+            }
+            None if self.stream.is_done() => Ok(Async::Ready(None)),
+            None => Ok(Async::NotReady),
+        }
+    }
+}
@@ -127,4 +127,8 @@ impl<S> ::sink::Sink for BufferUnordered<S>
     fn close(&mut self) -> Poll<(), S::SinkError> {
         self.stream.close()
     }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
 }
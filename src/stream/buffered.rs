@@ -93,6 +93,10 @@ impl<S> ::sink::Sink for Buffered<S>
     fn close(&mut self) -> Poll<(), S::SinkError> {
         self.stream.close()
     }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
 }
 
 impl<S> Stream for Buffered<S>
@@ -0,0 +1,183 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use {Async, Future, IntoFuture, Poll};
+use stream::{Stream, Fuse, FuturesUnordered};
+
+/// An adaptor for a stream of futures to execute the futures concurrently,
+/// returning their results in order while letting futures which complete
+/// early make room for new ones.
+///
+/// This is created by the `Stream::buffered_ordered` method.
+#[must_use = "streams do nothing unless polled"]
+pub struct BufferedOrdered<S>
+    where S: Stream,
+          S::Item: IntoFuture,
+{
+    stream: Fuse<S>,
+    in_flight: FuturesUnordered<Indexed<<S::Item as IntoFuture>::Future>>,
+    done: BTreeMap<usize, FutureResult<S::Item>>,
+    next_in: usize,
+    next_out: usize,
+    concurrency: usize,
+    window: usize,
+}
+
+impl<S> fmt::Debug for BufferedOrdered<S>
+    where S: Stream + fmt::Debug,
+          S::Item: IntoFuture,
+          <<S as Stream>::Item as IntoFuture>::Future: fmt::Debug,
+          <<S as Stream>::Item as IntoFuture>::Item: fmt::Debug,
+          <<S as Stream>::Item as IntoFuture>::Error: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("BufferedOrdered")
+            .field("stream", &self.stream)
+            .field("in_flight", &self.in_flight)
+            .field("done", &self.done)
+            .field("concurrency", &self.concurrency)
+            .field("window", &self.window)
+            .finish()
+    }
+}
+
+type FutureResult<F> = Result<<F as IntoFuture>::Item, <F as IntoFuture>::Error>;
+
+/// A future which resolves to the result of the inner future, tagged with
+/// its position in the underlying stream.
+#[derive(Debug)]
+struct Indexed<F> {
+    future: F,
+    index: usize,
+}
+
+impl<F: Future> Future for Indexed<F> {
+    type Item = (usize, Result<F::Item, F::Error>);
+    type Error = F::Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, F::Error> {
+        let result = match self.future.poll() {
+            Ok(Async::Ready(item)) => Ok(item),
+            Ok(Async::NotReady) => return Ok(Async::NotReady),
+            Err(e) => Err(e),
+        };
+        Ok(Async::Ready((self.index, result)))
+    }
+}
+
+pub fn new<S>(s: S, concurrency: usize, window: usize) -> BufferedOrdered<S>
+    where S: Stream,
+          S::Item: IntoFuture<Error=<S as Stream>::Error>,
+{
+    assert!(concurrency > 0, "concurrency must be greater than zero");
+    BufferedOrdered {
+        stream: super::fuse::new(s),
+        in_flight: FuturesUnordered::new(),
+        done: BTreeMap::new(),
+        next_in: 0,
+        next_out: 0,
+        concurrency: concurrency,
+        window: window,
+    }
+}
+
+impl<S> BufferedOrdered<S>
+    where S: Stream,
+          S::Item: IntoFuture<Error=<S as Stream>::Error>,
+{
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &S {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut S {
+        self.stream.get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S> ::sink::Sink for BufferedOrdered<S>
+    where S: ::sink::Sink + Stream,
+          S::Item: IntoFuture,
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem) -> ::StartSend<S::SinkItem, S::SinkError> {
+        self.stream.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
+}
+
+impl<S> Stream for BufferedOrdered<S>
+    where S: Stream,
+          S::Item: IntoFuture<Error=<S as Stream>::Error>,
+{
+    type Item = <S::Item as IntoFuture>::Item;
+    type Error = <S as Stream>::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        loop {
+            if let Some(result) = self.done.remove(&self.next_out) {
+                self.next_out += 1;
+                return result.map(|item| Async::Ready(Some(item)))
+            }
+
+            // Start as many futures as we're allowed to. Besides the limit on
+            // futures running at once, results completed out of order may
+            // only take up `window` more positions than `buffered` allows.
+            while self.in_flight.len() < self.concurrency &&
+                  self.next_in - self.next_out < self.concurrency + self.window {
+                let future = match self.stream.poll()? {
+                    Async::Ready(Some(s)) => s.into_future(),
+                    Async::Ready(None) |
+                    Async::NotReady => break,
+                };
+                self.in_flight.push(Indexed {
+                    future: future,
+                    index: self.next_in,
+                });
+                self.next_in += 1;
+            }
+
+            match self.in_flight.poll()? {
+                Async::Ready(Some((index, result))) => {
+                    self.done.insert(index, result);
+                }
+                Async::Ready(None) => {
+                    return if self.stream.is_done() {
+                        Ok(Async::Ready(None))
+                    } else {
+                        Ok(Async::NotReady)
+                    }
+                }
+                Async::NotReady => return Ok(Async::NotReady),
+            }
+        }
+    }
+}
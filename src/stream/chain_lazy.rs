@@ -0,0 +1,64 @@
+use core::mem;
+
+use stream::Stream;
+use {Async, Poll};
+
+/// State of chain_lazy stream.
+#[derive(Debug)]
+enum State<S1, S2, F> {
+    /// Emitting elements of first stream
+    First(S1, F),
+    /// Emitting elements of second stream
+    Second(S2),
+    /// The first stream failed, or the second one is being constructed
+    Done,
+}
+
+/// An adapter for chaining the output of a stream with a stream which is only
+/// constructed once the first one has ended.
+///
+/// This stream is created by the `Stream::chain_lazy` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct ChainLazy<S1, S2, F> {
+    state: State<S1, S2, F>,
+}
+
+pub fn new<S1, S2, F>(s1: S1, f: F) -> ChainLazy<S1, S2, F>
+    where S1: Stream,
+          F: FnOnce() -> S2,
+          S2: Stream<Item = S1::Item, Error = S1::Error>,
+{
+    ChainLazy { state: State::First(s1, f) }
+}
+
+impl<S1, S2, F> Stream for ChainLazy<S1, S2, F>
+    where S1: Stream,
+          F: FnOnce() -> S2,
+          S2: Stream<Item = S1::Item, Error = S1::Error>,
+{
+    type Item = S1::Item;
+    type Error = S1::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        loop {
+            match self.state {
+                State::First(ref mut s1, _) => match s1.poll() {
+                    Ok(Async::Ready(None)) => (), // roll
+                    Err(e) => {
+                        self.state = State::Done;
+                        return Err(e)
+                    }
+                    x => return x,
+                },
+                State::Second(ref mut s2) => return s2.poll(),
+                State::Done => return Ok(Async::Ready(None)),
+            }
+
+            self.state = match mem::replace(&mut self.state, State::Done) {
+                State::First(_s1, f) => State::Second(f()),
+                _ => unreachable!(),
+            };
+        }
+    }
+}
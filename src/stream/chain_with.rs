@@ -0,0 +1,70 @@
+use core::mem;
+
+use stream::Stream;
+use {Async, Poll};
+
+/// State of chain_with stream.
+#[derive(Debug)]
+enum State<S1, S2, F, T> {
+    /// Emitting elements of first stream, remembering the last one
+    First(S1, F, Option<T>),
+    /// Emitting elements of second stream
+    Second(S2),
+    /// The first stream failed, or the second one is being constructed
+    Done,
+}
+
+/// An adapter for chaining the output of a stream with a stream constructed
+/// from its last item.
+///
+/// This stream is created by the `Stream::chain_with` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct ChainWith<S1: Stream, S2, F> {
+    state: State<S1, S2, F, S1::Item>,
+}
+
+pub fn new<S1, S2, F>(s1: S1, f: F) -> ChainWith<S1, S2, F>
+    where S1: Stream,
+          S1::Item: Clone,
+          F: FnOnce(Option<S1::Item>) -> S2,
+          S2: Stream<Item = S1::Item, Error = S1::Error>,
+{
+    ChainWith { state: State::First(s1, f, None) }
+}
+
+impl<S1, S2, F> Stream for ChainWith<S1, S2, F>
+    where S1: Stream,
+          S1::Item: Clone,
+          F: FnOnce(Option<S1::Item>) -> S2,
+          S2: Stream<Item = S1::Item, Error = S1::Error>,
+{
+    type Item = S1::Item;
+    type Error = S1::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        loop {
+            match self.state {
+                State::First(ref mut s1, _, ref mut last) => match s1.poll() {
+                    Ok(Async::Ready(Some(item))) => {
+                        *last = Some(item.clone());
+                        return Ok(Async::Ready(Some(item)))
+                    }
+                    Ok(Async::Ready(None)) => (), // roll
+                    Ok(Async::NotReady) => return Ok(Async::NotReady),
+                    Err(e) => {
+                        self.state = State::Done;
+                        return Err(e)
+                    }
+                },
+                State::Second(ref mut s2) => return s2.poll(),
+                State::Done => return Ok(Async::Ready(None)),
+            }
+
+            self.state = match mem::replace(&mut self.state, State::Done) {
+                State::First(_s1, f, last) => State::Second(f(last)),
+                _ => unreachable!(),
+            };
+        }
+    }
+}
@@ -49,6 +49,10 @@ impl<S> ::sink::Sink for Chunks<S>
     fn close(&mut self) -> Poll<(), S::SinkError> {
         self.stream.close()
     }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
 }
 
 
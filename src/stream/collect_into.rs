@@ -0,0 +1,52 @@
+use {Future, Poll, Async};
+use stream::Stream;
+
+/// A future which collects all of the values of a stream into a container
+/// provided by the caller.
+///
+/// This future is created by the `Stream::collect_into` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct CollectInto<S, C> {
+    stream: S,
+    items: Option<C>,
+}
+
+pub fn new<S, C>(s: S, c: C) -> CollectInto<S, C>
+    where S: Stream,
+          C: Extend<S::Item>,
+{
+    CollectInto {
+        stream: s,
+        items: Some(c),
+    }
+}
+
+impl<S, C> Future for CollectInto<S, C>
+    where S: Stream,
+          C: Extend<S::Item>,
+{
+    type Item = C;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<C, S::Error> {
+        loop {
+            match self.stream.poll() {
+                Ok(Async::Ready(Some(e))) => {
+                    self.items.as_mut()
+                        .expect("cannot poll CollectInto twice")
+                        .extend(Some(e));
+                }
+                Ok(Async::Ready(None)) => {
+                    let items = self.items.take().expect("cannot poll CollectInto twice");
+                    return Ok(Async::Ready(items))
+                }
+                Ok(Async::NotReady) => return Ok(Async::NotReady),
+                Err(e) => {
+                    self.items = None;
+                    return Err(e)
+                }
+            }
+        }
+    }
+}
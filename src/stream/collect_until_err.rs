@@ -0,0 +1,57 @@
+use std::prelude::v1::*;
+
+use std::mem;
+
+use {Future, Poll, Async};
+use stream::Stream;
+
+/// A future which collects the successful values of a stream of `Result`s
+/// into a vector, up to the first failed one.
+///
+/// This future is created by the `Stream::collect_until_err` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct CollectUntilErr<S, T> {
+    stream: S,
+    items: Vec<T>,
+}
+
+pub fn new<S, T, E>(s: S) -> CollectUntilErr<S, T>
+    where S: Stream<Item = Result<T, E>>,
+{
+    CollectUntilErr {
+        stream: s,
+        items: Vec::new(),
+    }
+}
+
+impl<S, T> CollectUntilErr<S, T> {
+    fn finish(&mut self) -> Vec<T> {
+        mem::replace(&mut self.items, Vec::new())
+    }
+}
+
+impl<S, T, E> Future for CollectUntilErr<S, T>
+    where S: Stream<Item = Result<T, E>>,
+{
+    type Item = (Vec<T>, Option<(usize, E)>);
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, S::Error> {
+        loop {
+            match self.stream.poll() {
+                Ok(Async::Ready(Some(Ok(e)))) => self.items.push(e),
+                Ok(Async::Ready(Some(Err(e)))) => {
+                    let index = self.items.len();
+                    return Ok(Async::Ready((self.finish(), Some((index, e)))))
+                }
+                Ok(Async::Ready(None)) => return Ok(Async::Ready((self.finish(), None))),
+                Ok(Async::NotReady) => return Ok(Async::NotReady),
+                Err(e) => {
+                    self.finish();
+                    return Err(e)
+                }
+            }
+        }
+    }
+}
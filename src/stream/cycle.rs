@@ -0,0 +1,123 @@
+use std::prelude::v1::*;
+
+use {Async, Poll};
+use stream::{Stream, Fuse};
+
+/// A stream which repeats the items of an underlying stream over and over.
+///
+/// This structure is produced by the `Stream::cycle` and `Stream::cycle_n`
+/// methods.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Cycle<S> where S: Stream {
+    stream: Fuse<S>,
+    buffer: Vec<S::Item>,
+    pos: usize,
+    passes: usize,
+    limit: Option<usize>,
+}
+
+pub fn new<S>(s: S, limit: Option<usize>) -> Cycle<S>
+    where S: Stream,
+          S::Item: Clone,
+{
+    Cycle {
+        stream: s.fuse(),
+        buffer: Vec::new(),
+        pos: 0,
+        passes: 1,
+        limit: limit,
+    }
+}
+
+impl<S> Cycle<S> where S: Stream {
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &S {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut S {
+        self.stream.get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+
+    fn limit_reached(&self) -> bool {
+        match self.limit {
+            Some(limit) => self.passes > limit,
+            None => false,
+        }
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S> ::sink::Sink for Cycle<S>
+    where S: ::sink::Sink + Stream
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem) -> ::StartSend<S::SinkItem, S::SinkError> {
+        self.stream.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
+}
+
+impl<S> Stream for Cycle<S>
+    where S: Stream,
+          S::Item: Clone,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<S::Item>, S::Error> {
+        if self.limit_reached() {
+            return Ok(Async::Ready(None))
+        }
+
+        // The first pass goes through the underlying stream, remembering
+        // every item for the passes which follow.
+        if !self.stream.is_done() {
+            match try_ready!(self.stream.poll()) {
+                Some(item) => {
+                    self.buffer.push(item.clone());
+                    return Ok(Async::Ready(Some(item)))
+                }
+                None => self.passes += 1,
+            }
+        } else if self.pos == self.buffer.len() {
+            self.passes += 1;
+            self.pos = 0;
+        }
+
+        if self.buffer.is_empty() || self.limit_reached() {
+            return Ok(Async::Ready(None))
+        }
+        let item = self.buffer[self.pos].clone();
+        self.pos += 1;
+        Ok(Async::Ready(Some(item)))
+    }
+}
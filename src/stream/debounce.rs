@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+use {Async, Future, Poll};
+use stream::{Stream, Fuse};
+use task;
+use timer::Timer;
+
+/// The most items taken off the debounced stream in one call to `poll`, so
+/// that a stream which is always ready can't keep `poll` from returning.
+const MAX_DRAIN: usize = 32;
+
+/// A stream combinator which only lets items through after the underlying
+/// stream has been quiet for a while, collapsing bursts of items into one.
+///
+/// This stream is created by the `Stream::debounce` and
+/// `Stream::debounce_leading` methods.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Debounce<S, T> where S: Stream, T: Timer {
+    stream: Fuse<S>,
+    quiet: Duration,
+    timer: T,
+    delay: Option<T::Delay>,
+    pending: Option<S::Item>,
+    leading: bool,
+}
+
+pub fn new<S, T>(stream: S, quiet: Duration, timer: T, leading: bool)
+    -> Debounce<S, T>
+    where S: Stream,
+          T: Timer,
+{
+    Debounce {
+        stream: stream.fuse(),
+        quiet: quiet,
+        timer: timer,
+        delay: None,
+        pending: None,
+        leading: leading,
+    }
+}
+
+impl<S, T> Debounce<S, T> where S: Stream, T: Timer {
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &S {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut S {
+        self.stream.get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+}
+
+impl<S, T> Stream for Debounce<S, T>
+    where S: Stream,
+          T: Timer,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<S::Item>, S::Error> {
+        // Every new item restarts the quiet period.
+        let mut drained = 0;
+        while let Async::Ready(Some(item)) = self.stream.poll()? {
+            let in_burst = self.delay.is_some();
+            self.delay = Some(self.timer.delay(self.timer.now() + self.quiet));
+            if self.leading {
+                if !in_burst {
+                    return Ok(Async::Ready(Some(item)))
+                }
+            } else {
+                self.pending = Some(item);
+            }
+
+            drained += 1;
+            if drained == MAX_DRAIN {
+                // The stream may have more items ready, come back for them
+                // once others have had a chance to run. The quiet period has
+                // only just restarted, so there's nothing to yield yet.
+                task::current().notify();
+                return Ok(Async::NotReady)
+            }
+        }
+
+        // There's nothing left to wait for once the stream has ended, so the
+        // last item of a trailing burst is flushed right away.
+        if self.stream.is_done() {
+            self.delay = None;
+            return Ok(Async::Ready(self.pending.take()))
+        }
+
+        // A timer which fails can't tell when the quiet period is over, so
+        // it's taken to be over right away.
+        let elapsed = match self.delay {
+            Some(ref mut delay) => match delay.poll() {
+                Ok(Async::NotReady) => false,
+                Ok(Async::Ready(())) | Err(()) => true,
+            },
+            None => false,
+        };
+        if elapsed {
+            self.delay = None;
+            if let Some(item) = self.pending.take() {
+                return Ok(Async::Ready(Some(item)))
+            }
+        }
+        Ok(Async::NotReady)
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, T> ::sink::Sink for Debounce<S, T>
+    where S: ::sink::Sink + Stream,
+          T: Timer,
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem) -> ::StartSend<S::SinkItem, S::SinkError> {
+        self.stream.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
+}
@@ -0,0 +1,88 @@
+use {Poll, Async};
+use stream::Stream;
+
+/// A stream combinator which pairs each element of a stream with its index.
+///
+/// This structure is produced by the `Stream::enumerate` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Enumerate<S> {
+    stream: S,
+    count: usize,
+}
+
+pub fn new<S>(s: S) -> Enumerate<S>
+    where S: Stream,
+{
+    Enumerate {
+        stream: s,
+        count: 0,
+    }
+}
+
+impl<S> Enumerate<S> {
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &S {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S> ::sink::Sink for Enumerate<S>
+    where S: ::sink::Sink
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem) -> ::StartSend<S::SinkItem, S::SinkError> {
+        self.stream.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
+}
+
+impl<S> Stream for Enumerate<S>
+    where S: Stream,
+{
+    type Item = (usize, S::Item);
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<(usize, S::Item)>, S::Error> {
+        match try_ready!(self.stream.poll()) {
+            Some(item) => {
+                let index = self.count;
+                self.count += 1;
+                Ok(Async::Ready(Some((index, item))))
+            }
+            None => Ok(Async::Ready(None)),
+        }
+    }
+}
@@ -65,6 +65,10 @@ impl<S, F> ::sink::Sink for Filter<S, F>
     fn close(&mut self) -> Poll<(), S::SinkError> {
         self.stream.close()
     }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
 }
 
 impl<S, F> Stream for Filter<S, F>
@@ -0,0 +1,112 @@
+use {Async, Future, IntoFuture, Poll};
+use stream::Stream;
+
+/// A combinator used to filter the results of a stream and simultaneously map
+/// them to a different type, deciding what to do with each item
+/// asynchronously.
+///
+/// This structure is returned by the `Stream::filter_map_async` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct FilterMapAsync<S, F, R> where R: IntoFuture {
+    stream: S,
+    f: F,
+    pending: Option<R::Future>,
+}
+
+pub fn new<S, F, R, B>(s: S, f: F) -> FilterMapAsync<S, F, R>
+    where S: Stream,
+          F: FnMut(S::Item) -> R,
+          R: IntoFuture<Item = Option<B>, Error = S::Error>,
+{
+    FilterMapAsync {
+        stream: s,
+        f: f,
+        pending: None,
+    }
+}
+
+impl<S, F, R> FilterMapAsync<S, F, R> where R: IntoFuture {
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &S {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, F, R> ::sink::Sink for FilterMapAsync<S, F, R>
+    where S: ::sink::Sink, R: IntoFuture
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem) -> ::StartSend<S::SinkItem, S::SinkError> {
+        self.stream.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
+}
+
+impl<S, F, R, B> Stream for FilterMapAsync<S, F, R>
+    where S: Stream,
+          F: FnMut(S::Item) -> R,
+          R: IntoFuture<Item = Option<B>, Error = S::Error>,
+{
+    type Item = B;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<B>, S::Error> {
+        loop {
+            if self.pending.is_none() {
+                let item = match try_ready!(self.stream.poll()) {
+                    Some(e) => e,
+                    None => return Ok(Async::Ready(None)),
+                };
+                self.pending = Some((self.f)(item).into_future());
+            }
+
+            assert!(self.pending.is_some());
+            let res = self.pending.as_mut().unwrap().poll();
+            match res {
+                Ok(Async::Ready(Some(b))) => {
+                    self.pending = None;
+                    return Ok(Async::Ready(Some(b)))
+                }
+                Ok(Async::Ready(None)) => self.pending = None,
+                Ok(Async::NotReady) => return Ok(Async::NotReady),
+                Err(e) => {
+                    self.pending = None;
+                    return Err(e)
+                }
+            }
+        }
+    }
+}
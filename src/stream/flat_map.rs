@@ -0,0 +1,101 @@
+use {Poll, Async};
+use stream::Stream;
+
+/// A combinator which maps each item of a stream to a stream, and flattens
+/// those into one long stream of elements.
+///
+/// This combinator is created by the `Stream::flat_map` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct FlatMap<S, F, U> {
+    stream: S,
+    f: F,
+    next: Option<U>,
+}
+
+pub fn new<S, F, U>(s: S, f: F) -> FlatMap<S, F, U>
+    where S: Stream,
+          F: FnMut(S::Item) -> U,
+          U: Stream,
+          U::Error: From<S::Error>,
+{
+    FlatMap {
+        stream: s,
+        f: f,
+        next: None,
+    }
+}
+
+impl<S, F, U> FlatMap<S, F, U> {
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &S {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, F, U> ::sink::Sink for FlatMap<S, F, U>
+    where S: ::sink::Sink
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem) -> ::StartSend<S::SinkItem, S::SinkError> {
+        self.stream.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
+}
+
+impl<S, F, U> Stream for FlatMap<S, F, U>
+    where S: Stream,
+          F: FnMut(S::Item) -> U,
+          U: Stream,
+          U::Error: From<S::Error>,
+{
+    type Item = U::Item;
+    type Error = U::Error;
+
+    fn poll(&mut self) -> Poll<Option<U::Item>, U::Error> {
+        loop {
+            if self.next.is_none() {
+                match try_ready!(self.stream.poll()) {
+                    Some(e) => self.next = Some((self.f)(e)),
+                    None => return Ok(Async::Ready(None)),
+                }
+            }
+            match self.next.as_mut().unwrap().poll() {
+                Ok(Async::Ready(None)) => self.next = None,
+                other => return other,
+            }
+        }
+    }
+}
@@ -68,6 +68,10 @@ impl<S> ::sink::Sink for Flatten<S>
     fn close(&mut self) -> Poll<(), S::SinkError> {
         self.stream.close()
     }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
 }
 
 impl<S> Stream for Flatten<S>
@@ -0,0 +1,148 @@
+use std::fmt;
+
+use {Async, Poll};
+use stream::{Stream, Fuse, FuturesUnordered, StreamFuture};
+
+/// An adaptor for a stream of streams which polls a bounded number of the
+/// inner streams concurrently, delivering their items as they become
+/// available.
+///
+/// This combinator is created by the `Stream::flatten_unordered` method.
+#[must_use = "streams do nothing unless polled"]
+pub struct FlattenUnordered<S>
+    where S: Stream,
+          S::Item: Stream,
+{
+    stream: Fuse<S>,
+    queue: FuturesUnordered<StreamFuture<S::Item>>,
+    max: usize,
+}
+
+impl<S> fmt::Debug for FlattenUnordered<S>
+    where S: Stream + fmt::Debug,
+          S::Item: Stream + fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("FlattenUnordered")
+            .field("stream", &self.stream)
+            .field("queue", &self.queue)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+pub fn new<S>(s: S, amt: usize) -> FlattenUnordered<S>
+    where S: Stream,
+          S::Item: Stream,
+          <S::Item as Stream>::Error: From<S::Error>,
+{
+    assert!(amt > 0, "amt must be greater than zero");
+    FlattenUnordered {
+        stream: super::fuse::new(s),
+        queue: FuturesUnordered::new(),
+        max: amt,
+    }
+}
+
+impl<S> FlattenUnordered<S>
+    where S: Stream,
+          S::Item: Stream,
+{
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &S {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut S {
+        self.stream.get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+}
+
+impl<S> Stream for FlattenUnordered<S>
+    where S: Stream,
+          S::Item: Stream,
+          <S::Item as Stream>::Error: From<S::Error>,
+{
+    type Item = <S::Item as Stream>::Item;
+    type Error = <S::Item as Stream>::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        loop {
+            // First up, pull in as many inner streams as we have room for.
+            while self.queue.len() < self.max {
+                let stream = match self.stream.poll()? {
+                    Async::Ready(Some(s)) => s,
+                    Async::Ready(None) |
+                    Async::NotReady => break,
+                };
+
+                self.queue.push(stream.into_future());
+            }
+
+            // Next see if any of the inner streams have something for us. A
+            // stream which yields an item or an error is put back into the
+            // set, and a finished stream frees up a slot so we go back around
+            // to try to fill it.
+            match self.queue.poll() {
+                Ok(Async::Ready(Some((Some(item), s)))) => {
+                    self.queue.push(s.into_future());
+                    return Ok(Async::Ready(Some(item)))
+                }
+                Ok(Async::Ready(Some((None, _)))) => {}
+                Ok(Async::Ready(None)) => break,
+                Ok(Async::NotReady) => return Ok(Async::NotReady),
+                Err((e, s)) => {
+                    self.queue.push(s.into_future());
+                    return Err(e)
+                }
+            }
+        }
+
+        // If we've gotten this far then all inner streams are exhausted, so
+        // we're done only if the outer stream is as well.
+        if self.stream.is_done() {
+            Ok(Async::Ready(None))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S> ::sink::Sink for FlattenUnordered<S>
+    where S: ::sink::Sink + Stream,
+          S::Item: Stream,
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem) -> ::StartSend<S::SinkItem, S::SinkError> {
+        self.stream.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
+}
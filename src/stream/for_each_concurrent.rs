@@ -0,0 +1,84 @@
+use std::fmt;
+
+use {Async, Future, IntoFuture, Poll};
+use stream::{Stream, Fuse, FuturesUnordered};
+
+/// A future which executes a unit closure over each item on a stream, with a
+/// bounded number of the resulting futures running at once.
+///
+/// This structure is returned by the `Stream::for_each_concurrent` method.
+#[must_use = "futures do nothing unless polled"]
+pub struct ForEachConcurrent<S, F, U> where U: IntoFuture {
+    stream: Fuse<S>,
+    f: F,
+    queue: FuturesUnordered<U::Future>,
+    limit: usize,
+}
+
+impl<S, F, U> fmt::Debug for ForEachConcurrent<S, F, U>
+    where S: fmt::Debug,
+          U: IntoFuture,
+          U::Future: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("ForEachConcurrent")
+            .field("stream", &self.stream)
+            .field("queue", &self.queue)
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+pub fn new<S, F, U>(s: S, limit: usize, f: F) -> ForEachConcurrent<S, F, U>
+    where S: Stream,
+          F: FnMut(S::Item) -> U,
+          U: IntoFuture<Item = (), Error = S::Error>,
+{
+    assert!(limit > 0, "limit must be greater than zero");
+    ForEachConcurrent {
+        stream: s.fuse(),
+        f: f,
+        queue: FuturesUnordered::new(),
+        limit: limit,
+    }
+}
+
+impl<S, F, U> Future for ForEachConcurrent<S, F, U>
+    where S: Stream,
+          F: FnMut(S::Item) -> U,
+          U: IntoFuture<Item = (), Error = S::Error>,
+{
+    type Item = ();
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<(), S::Error> {
+        loop {
+            // Only pull more items off the stream while there's room for them,
+            // leaving the rest of the stream to apply backpressure.
+            while self.queue.len() < self.limit {
+                match self.stream.poll()? {
+                    Async::Ready(Some(item)) => {
+                        self.queue.push((self.f)(item).into_future())
+                    }
+                    Async::Ready(None) |
+                    Async::NotReady => break,
+                }
+            }
+
+            match self.queue.poll() {
+                Ok(Async::Ready(Some(()))) => {}
+                Ok(Async::Ready(None)) => {
+                    if self.stream.is_done() {
+                        return Ok(Async::Ready(()))
+                    }
+                    return Ok(Async::NotReady)
+                }
+                Ok(Async::NotReady) => return Ok(Async::NotReady),
+                Err(e) => {
+                    self.queue = FuturesUnordered::new();
+                    return Err(e)
+                }
+            }
+        }
+    }
+}
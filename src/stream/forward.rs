@@ -10,18 +10,54 @@ pub struct Forward<T: Stream, U> {
     sink: Option<U>,
     stream: Option<Fuse<T>>,
     buffered: Option<T::Item>,
+    policy: FlushPolicy,
+    unflushed: usize,
 }
 
+/// When `Stream::forward_with` flushes the sink it's sending items to.
+///
+/// Whatever the policy, the sink is always flushed and closed once the stream
+/// has ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlushPolicy {
+    /// Flush the sink after every item sent to it.
+    EveryItem,
+
+    /// Flush the sink only when the stream has no item ready, coalescing a
+    /// burst of items into a single flush. This is what `Stream::forward`
+    /// does.
+    WhenSourcePending,
+
+    /// Flush the sink after every `n` items sent to it, regardless of whether
+    /// the stream has more items ready.
+    ///
+    /// Note that the items sent since the last flush are left unflushed while
+    /// the stream has no item ready, until more items complete the batch.
+    EveryN(usize),
+}
 
 pub fn new<T, U>(stream: T, sink: U) -> Forward<T, U>
     where U: Sink<SinkItem=T::Item>,
           T: Stream,
           T::Error: From<U::SinkError>,
 {
+    with_policy(stream, sink, FlushPolicy::WhenSourcePending)
+}
+
+pub fn with_policy<T, U>(stream: T, sink: U, policy: FlushPolicy) -> Forward<T, U>
+    where U: Sink<SinkItem=T::Item>,
+          T: Stream,
+          T::Error: From<U::SinkError>,
+{
+    if let FlushPolicy::EveryN(n) = policy {
+        assert!(n > 0, "cannot flush every 0 items");
+    }
     Forward {
         sink: Some(sink),
         stream: Some(stream.fuse()),
         buffered: None,
+        policy: policy,
+        unflushed: 0,
     }
 }
 
@@ -54,8 +90,23 @@ impl<T, U> Forward<T, U>
             self.buffered = Some(item);
             return Ok(Async::NotReady)
         }
+        self.unflushed += 1;
         Ok(Async::Ready(()))
     }
+
+    fn poll_flush(&mut self) -> Poll<(), U::SinkError> {
+        try_ready!(self.sink_mut().poll_complete());
+        self.unflushed = 0;
+        Ok(Async::Ready(()))
+    }
+
+    fn batch_full(&self) -> bool {
+        match self.policy {
+            FlushPolicy::EveryItem => self.unflushed > 0,
+            FlushPolicy::WhenSourcePending => false,
+            FlushPolicy::EveryN(n) => self.unflushed >= n,
+        }
+    }
 }
 
 impl<T, U> Future for Forward<T, U>
@@ -74,6 +125,10 @@ impl<T, U> Future for Forward<T, U>
         }
 
         loop {
+            if self.batch_full() {
+                try_ready!(self.poll_flush());
+            }
+
             match self.stream_mut().poll()? {
                 Async::Ready(Some(item)) => try_ready!(self.try_start_send(item)),
                 Async::Ready(None) => {
@@ -81,7 +136,9 @@ impl<T, U> Future for Forward<T, U>
                     return Ok(Async::Ready(self.take_result()))
                 }
                 Async::NotReady => {
-                    try_ready!(self.sink_mut().poll_complete());
+                    if self.policy == FlushPolicy::WhenSourcePending {
+                        try_ready!(self.poll_flush());
+                    }
                     return Ok(Async::NotReady)
                 }
             }
@@ -77,4 +77,8 @@ impl<S: Stream + ::sink::Sink, E> ::sink::Sink for FromErr<S, E> {
     fn close(&mut self) -> Poll<(), Self::SinkError> {
         self.stream.close()
     }
+
+    fn poll_ready(&mut self) -> Poll<(), Self::SinkError> {
+        self.stream.poll_ready()
+    }
 }
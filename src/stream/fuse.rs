@@ -31,6 +31,10 @@ impl<S> ::sink::Sink for Fuse<S>
     fn close(&mut self) -> Poll<(), S::SinkError> {
         self.stream.close()
     }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
 }
 
 pub fn new<S: Stream>(s: S) -> Fuse<S> {
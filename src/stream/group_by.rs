@@ -0,0 +1,373 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+use std::mem;
+use std::sync::{Arc, Mutex};
+
+use {Async, Poll};
+use executor::{self, Notify, Spawn};
+use stream::Stream;
+use task::{self, Task};
+
+/// How items are buffered for each group produced by `Stream::group_by`.
+///
+/// Every group keeps a queue of the items which have been pulled from the
+/// underlying stream but not yet taken by that group's consumer. This controls
+/// what happens when a consumer falls behind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBuffering {
+    /// Each group buffers an unlimited number of items.
+    Unbounded,
+    /// Each group buffers at most this many items. Once an item arrives for a
+    /// full group the underlying stream is no longer polled, for any group,
+    /// until that group's consumer has taken an item.
+    Bounded(usize),
+    /// Each group buffers at most this many items. Once an item arrives for a
+    /// full group the oldest buffered item of that group is discarded.
+    DropOldest(usize),
+}
+
+/// A stream which splits the items of an underlying stream into keyed groups.
+///
+/// Each item yielded by this stream is a key paired with a `GroupStream`
+/// delivering the items which share that key.
+///
+/// This stream is created by the `Stream::group_by` method.
+#[must_use = "streams do nothing unless polled"]
+pub struct GroupBy<S, K, F>
+    where S: Stream,
+          K: Hash + Eq,
+{
+    shared: Arc<Shared<S, K, F>>,
+}
+
+/// A stream of the items for one key of a `GroupBy` stream.
+///
+/// These streams are the values yielded by `GroupBy`.
+#[must_use = "streams do nothing unless polled"]
+pub struct GroupStream<S, K, F>
+    where S: Stream,
+          K: Hash + Eq,
+{
+    shared: Arc<Shared<S, K, F>>,
+    key: K,
+    id: usize,
+}
+
+struct Shared<S: Stream, K, F> {
+    state: Mutex<State<S, K, F>>,
+    notifier: Arc<Notifier>,
+}
+
+struct State<S: Stream, K, F> {
+    stream: Spawn<S>,
+    done: bool,
+    key_fn: F,
+    buffering: GroupBuffering,
+    groups: HashMap<K, Group<S::Item>>,
+    new_groups: VecDeque<K>,
+    blocked: Option<(K, S::Item)>,
+    outer_alive: bool,
+    next_id: usize,
+}
+
+struct Group<T> {
+    id: usize,
+    buffer: VecDeque<T>,
+}
+
+/// Tasks of the `GroupBy` and `GroupStream` handles waiting on this set of
+/// groups. The `GroupBy` is always registered under id 0.
+struct Notifier {
+    waiters: Mutex<HashMap<usize, Task>>,
+}
+
+const OUTER: usize = 0;
+
+pub fn new<S, K, F>(s: S, f: F) -> GroupBy<S, K, F>
+    where S: Stream,
+          K: Hash + Eq + Clone,
+          F: FnMut(&S::Item) -> K,
+{
+    GroupBy {
+        shared: Arc::new(Shared {
+            state: Mutex::new(State {
+                stream: executor::spawn(s),
+                done: false,
+                key_fn: f,
+                buffering: GroupBuffering::Unbounded,
+                groups: HashMap::new(),
+                new_groups: VecDeque::new(),
+                blocked: None,
+                outer_alive: true,
+                next_id: OUTER + 1,
+            }),
+            notifier: Arc::new(Notifier {
+                waiters: Mutex::new(HashMap::new()),
+            }),
+        }),
+    }
+}
+
+impl<S, K, F> GroupBy<S, K, F>
+    where S: Stream,
+          K: Hash + Eq + Clone,
+          F: FnMut(&S::Item) -> K,
+{
+    /// Configures how many items each group may buffer, and what happens when
+    /// a group's buffer is full.
+    ///
+    /// By default every group buffers an unbounded number of items.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if a `Bounded` or `DropOldest` capacity of zero
+    /// is provided.
+    pub fn buffering(self, buffering: GroupBuffering) -> GroupBy<S, K, F> {
+        match buffering {
+            GroupBuffering::Bounded(0) |
+            GroupBuffering::DropOldest(0) => panic!("group capacity cannot be zero"),
+            _ => {}
+        }
+        self.shared.state.lock().unwrap().buffering = buffering;
+        self
+    }
+}
+
+impl<S, K, F> Stream for GroupBy<S, K, F>
+    where S: Stream,
+          K: Hash + Eq + Clone,
+          F: FnMut(&S::Item) -> K,
+{
+    type Item = (K, GroupStream<S, K, F>);
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, S::Error> {
+        let mut state = self.shared.state.lock().unwrap();
+        loop {
+            if let Some(key) = state.new_groups.pop_front() {
+                self.shared.notifier.remove(OUTER);
+                let id = state.groups[&key].id;
+                let group = GroupStream {
+                    shared: self.shared.clone(),
+                    key: key.clone(),
+                    id: id,
+                };
+                return Ok(Async::Ready(Some((key, group))))
+            }
+            if state.done {
+                self.shared.notifier.remove(OUTER);
+                return Ok(Async::Ready(None))
+            }
+            try_ready!(state.poll_stream(&self.shared.notifier, OUTER));
+        }
+    }
+}
+
+impl<S, K, F> Drop for GroupBy<S, K, F>
+    where S: Stream,
+          K: Hash + Eq,
+{
+    fn drop(&mut self) {
+        // Nobody is left to hand out new groups, so forget about the ones
+        // which were never yielded and discard items for new keys from here on.
+        let mut state = match self.shared.state.lock() {
+            Ok(state) => state,
+            Err(_) => return,
+        };
+        state.outer_alive = false;
+        while let Some(key) = state.new_groups.pop_front() {
+            state.groups.remove(&key);
+        }
+        drop(state);
+        self.shared.notifier.remove(OUTER);
+        self.shared.notifier.notify(OUTER);
+    }
+}
+
+impl<S, K, F> GroupStream<S, K, F>
+    where S: Stream,
+          K: Hash + Eq,
+{
+    /// Returns the key shared by all of the items of this group.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+}
+
+impl<S, K, F> Stream for GroupStream<S, K, F>
+    where S: Stream,
+          K: Hash + Eq + Clone,
+          F: FnMut(&S::Item) -> K,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<S::Item>, S::Error> {
+        let mut state = self.shared.state.lock().unwrap();
+        loop {
+            let item = state.groups.get_mut(&self.key)
+                .and_then(|g| g.buffer.pop_front());
+            if let Some(item) = item {
+                // If the stream was paused because this group was full then
+                // there's room again, so let everyone try to make progress.
+                let unblocked = match state.blocked {
+                    Some((ref key, _)) => *key == self.key,
+                    None => false,
+                };
+                self.shared.notifier.remove(self.id);
+                if unblocked {
+                    drop(state);
+                    self.shared.notifier.notify(self.id);
+                }
+                return Ok(Async::Ready(Some(item)))
+            }
+            if state.done {
+                self.shared.notifier.remove(self.id);
+                return Ok(Async::Ready(None))
+            }
+            try_ready!(state.poll_stream(&self.shared.notifier, self.id));
+        }
+    }
+}
+
+impl<S, K, F> Drop for GroupStream<S, K, F>
+    where S: Stream,
+          K: Hash + Eq,
+{
+    fn drop(&mut self) {
+        // Any items for this key which arrive later on will start a new group.
+        // If the stream was paused on this group it's no longer, so wake
+        // everyone up to try again.
+        let mut state = match self.shared.state.lock() {
+            Ok(state) => state,
+            Err(_) => return,
+        };
+        state.groups.remove(&self.key);
+        drop(state);
+        self.shared.notifier.remove(self.id);
+        self.shared.notifier.notify(self.id);
+    }
+}
+
+impl<S, K, F> State<S, K, F>
+    where S: Stream,
+          K: Hash + Eq + Clone,
+          F: FnMut(&S::Item) -> K,
+{
+    /// Attempts to pull one more item out of the underlying stream and route
+    /// it to its group on behalf of the handle `id`.
+    ///
+    /// Returns `Ready` if some progress was made, in which case the caller
+    /// should check its own buffer again.
+    fn poll_stream(&mut self, notifier: &Arc<Notifier>, id: usize) -> Poll<(), S::Error> {
+        notifier.insert(id, task::current());
+
+        if let Some((key, item)) = self.blocked.take() {
+            if self.is_full(&key) {
+                self.blocked = Some((key, item));
+                return Ok(Async::NotReady)
+            }
+            self.route(notifier, key, item);
+            return Ok(Async::Ready(()))
+        }
+
+        match self.stream.poll_stream_notify(notifier, 0)? {
+            Async::Ready(Some(item)) => {
+                let key = (self.key_fn)(&item);
+                if self.is_full(&key) {
+                    self.blocked = Some((key, item));
+                } else {
+                    self.route(notifier, key, item);
+                }
+                Ok(Async::Ready(()))
+            }
+            Async::Ready(None) => {
+                self.done = true;
+                notifier.notify(id);
+                Ok(Async::Ready(()))
+            }
+            Async::NotReady => Ok(Async::NotReady),
+        }
+    }
+
+    fn is_full(&self, key: &K) -> bool {
+        match (self.buffering, self.groups.get(key)) {
+            (GroupBuffering::Bounded(cap), Some(group)) => group.buffer.len() >= cap,
+            _ => false,
+        }
+    }
+
+    fn route(&mut self, notifier: &Notifier, key: K, item: S::Item) {
+        if let Some(group) = self.groups.get_mut(&key) {
+            if let GroupBuffering::DropOldest(cap) = self.buffering {
+                if group.buffer.len() >= cap {
+                    group.buffer.pop_front();
+                }
+            }
+            group.buffer.push_back(item);
+            notifier.wake(group.id);
+            return
+        }
+
+        if !self.outer_alive {
+            return
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        let mut buffer = VecDeque::new();
+        buffer.push_back(item);
+        self.groups.insert(key.clone(), Group { id: id, buffer: buffer });
+        self.new_groups.push_back(key);
+        notifier.wake(OUTER);
+    }
+}
+
+impl Notifier {
+    fn insert(&self, id: usize, task: Task) {
+        self.waiters.lock().unwrap().insert(id, task);
+    }
+
+    fn remove(&self, id: usize) {
+        self.waiters.lock().unwrap().remove(&id);
+    }
+
+    fn wake(&self, id: usize) {
+        let task = self.waiters.lock().unwrap().remove(&id);
+        if let Some(task) = task {
+            task.notify();
+        }
+    }
+}
+
+impl Notify for Notifier {
+    fn notify(&self, _id: usize) {
+        let waiters = mem::replace(&mut *self.waiters.lock().unwrap(), HashMap::new());
+
+        for (_, waiter) in waiters {
+            waiter.notify();
+        }
+    }
+}
+
+impl<S, K, F> fmt::Debug for GroupBy<S, K, F>
+    where S: Stream,
+          K: Hash + Eq,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("GroupBy")
+            .finish()
+    }
+}
+
+impl<S, K, F> fmt::Debug for GroupStream<S, K, F>
+    where S: Stream,
+          K: Hash + Eq + fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("GroupStream")
+            .field("key", &self.key)
+            .finish()
+    }
+}
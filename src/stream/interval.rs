@@ -0,0 +1,94 @@
+use std::time::{Duration, Instant};
+
+use {Async, Future, Poll};
+use stream::Stream;
+use timer::Timer;
+
+/// A stream which yields `()` at a fixed period.
+///
+/// This stream is created by the `stream::interval` and
+/// `stream::interval_with` functions.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Interval<T: Timer> {
+    timer: T,
+    period: Duration,
+    next: Instant,
+    delay: T::Delay,
+    missed: MissedTicks,
+}
+
+/// What an `Interval` does about ticks which were missed because it wasn't
+/// polled in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissedTicks {
+    /// Yield a single tick for all of the missed ones, and carry on with the
+    /// next deadline which is still ahead. This is what `stream::interval`
+    /// does.
+    Skip,
+
+    /// Yield every missed tick right away, one after the other, until the
+    /// interval has caught up.
+    CatchUp,
+}
+
+/// Creates a stream which yields `()` every `period`, measured with `timer`.
+///
+/// The first tick is yielded one `period` from now. Every following deadline
+/// is computed from the previous deadline rather than from when the previous
+/// tick was actually yielded, so lateness doesn't accumulate. If the stream
+/// isn't polled for longer than a period, the missed ticks are coalesced into
+/// a single one.
+///
+/// The stream never ends, and fails if the timer does.
+///
+/// This function is only available when the `use_std` feature of this library
+/// is activated, and it is activated by default.
+///
+/// # Panics
+///
+/// This function will panic if `period` is zero.
+pub fn interval<T: Timer>(period: Duration, timer: T) -> Interval<T> {
+    interval_with(period, timer, MissedTicks::Skip)
+}
+
+/// Creates a stream which yields `()` every `period`, measured with `timer`,
+/// and handles missed ticks according to `missed`.
+///
+/// This is the same as `stream::interval`, which uses `MissedTicks::Skip`.
+///
+/// # Panics
+///
+/// This function will panic if `period` is zero.
+pub fn interval_with<T: Timer>(period: Duration, timer: T, missed: MissedTicks)
+    -> Interval<T>
+{
+    assert!(period > Duration::from_secs(0), "interval period cannot be zero");
+    let next = timer.now() + period;
+    Interval {
+        delay: timer.delay(next),
+        timer: timer,
+        period: period,
+        next: next,
+        missed: missed,
+    }
+}
+
+impl<T: Timer> Stream for Interval<T> {
+    type Item = ();
+    type Error = ();
+
+    fn poll(&mut self) -> Poll<Option<()>, ()> {
+        try_ready!(self.delay.poll());
+
+        self.next += self.period;
+        if self.missed == MissedTicks::Skip {
+            let now = self.timer.now();
+            while self.next < now {
+                self.next += self.period;
+            }
+        }
+        self.delay = self.timer.delay(self.next);
+        Ok(Async::Ready(Some(())))
+    }
+}
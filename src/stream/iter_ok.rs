@@ -19,6 +19,10 @@ pub struct IterOk<I, E> {
 /// Iterators in Rust don't express the ability to block, so this adapter
 /// simply always calls `iter.next()` and returns that.
 ///
+/// The stream never fails, its error type `E` is only there to fit in with
+/// other streams. To turn an iterator over `Result`s into a stream which
+/// fails on their `Err` values use `iter_result` instead.
+///
 /// ```rust
 /// use futures::*;
 ///
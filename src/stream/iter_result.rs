@@ -16,6 +16,10 @@ pub struct IterResult<I> {
 /// Iterators in Rust don't express the ability to block, so this adapter simply
 /// always calls `iter.next()` and returns that.
 ///
+/// Every `Ok` value is yielded as an item of the stream and every `Err` value
+/// as an error. An error doesn't end the stream, the items after it are still
+/// yielded. For an iterator over plain values, use `iter_ok` instead.
+///
 /// ```rust
 /// use futures::*;
 ///
@@ -0,0 +1,42 @@
+use {Future, Poll, Async};
+use stream::Stream;
+
+/// A future which resolves to the last item of a stream.
+///
+/// This future is created by the `Stream::last` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Last<S> where S: Stream {
+    stream: S,
+    last: Option<S::Item>,
+}
+
+pub fn new<S>(s: S) -> Last<S>
+    where S: Stream,
+{
+    Last {
+        stream: s,
+        last: None,
+    }
+}
+
+impl<S> Future for Last<S>
+    where S: Stream,
+{
+    type Item = Option<S::Item>;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<S::Item>, S::Error> {
+        loop {
+            match self.stream.poll() {
+                Ok(Async::Ready(Some(e))) => self.last = Some(e),
+                Ok(Async::Ready(None)) => return Ok(Async::Ready(self.last.take())),
+                Ok(Async::NotReady) => return Ok(Async::NotReady),
+                Err(e) => {
+                    self.last = None;
+                    return Err(e)
+                }
+            }
+        }
+    }
+}
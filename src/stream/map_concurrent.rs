@@ -0,0 +1,108 @@
+use std::fmt;
+
+use {IntoFuture, Poll};
+use stream::{Stream, Map, TryBuffered};
+
+/// A stream combinator which maps each item to a future and runs a bounded
+/// number of them concurrently, yielding their results in order.
+///
+/// This is created by the `Stream::map_concurrent` method.
+#[must_use = "streams do nothing unless polled"]
+pub struct MapConcurrent<S, F>
+    where S: Stream,
+          Map<S, F>: Stream,
+          <Map<S, F> as Stream>::Item: IntoFuture,
+{
+    inner: TryBuffered<Map<S, F>>,
+}
+
+impl<S, F> fmt::Debug for MapConcurrent<S, F>
+    where S: Stream,
+          Map<S, F>: Stream,
+          <Map<S, F> as Stream>::Item: IntoFuture,
+          TryBuffered<Map<S, F>>: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("MapConcurrent")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+pub fn new<S, F, U>(s: S, amt: usize, f: F) -> MapConcurrent<S, F>
+    where S: Stream,
+          F: FnMut(S::Item) -> U,
+          U: IntoFuture<Error=S::Error>,
+{
+    MapConcurrent {
+        inner: super::try_buffered::new(super::map::new(s, f), amt),
+    }
+}
+
+impl<S, F, U> MapConcurrent<S, F>
+    where S: Stream,
+          F: FnMut(S::Item) -> U,
+          U: IntoFuture<Error=S::Error>,
+{
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &S {
+        self.inner.get_ref().get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut S {
+        self.inner.get_mut().get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> S {
+        self.inner.into_inner().into_inner()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, F, U> ::sink::Sink for MapConcurrent<S, F>
+    where S: ::sink::Sink + Stream,
+          F: FnMut(S::Item) -> U,
+          U: IntoFuture,
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem) -> ::StartSend<S::SinkItem, S::SinkError> {
+        self.inner.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.inner.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.inner.close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.inner.poll_ready()
+    }
+}
+
+impl<S, F, U> Stream for MapConcurrent<S, F>
+    where S: Stream,
+          F: FnMut(S::Item) -> U,
+          U: IntoFuture<Error=S::Error>,
+{
+    type Item = U::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<U::Item>, S::Error> {
+        self.inner.poll()
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt;
+use std::prelude::v1::*;
+
+use {Async, Poll};
+use stream::{Stream, Fuse};
+
+/// A stream which merges a number of sorted streams into a single sorted
+/// stream.
+///
+/// This stream is created by the `stream::merge_ordered` function.
+#[must_use = "streams do nothing unless polled"]
+pub struct MergeOrdered<S: Stream, F> {
+    streams: Vec<(Fuse<S>, Option<S::Item>)>,
+    key_fn: F,
+}
+
+/// Merges a list of streams, each of which is sorted by the key computed with
+/// `key_fn`, into a single stream sorted by that key.
+///
+/// The head of every stream is buffered and the one with the smallest key is
+/// yielded next, after which only the stream it came from is polled again. An
+/// item can therefore only be yielded once every stream which hasn't ended
+/// yet has an item buffered. Items with equal keys are yielded in the order of
+/// the streams in `streams`.
+///
+/// The streams are assumed to already be sorted by the key. If one of them
+/// isn't, the output won't be sorted either, but no error is reported.
+///
+/// Errors from any of the streams are passed through immediately.
+pub fn merge_ordered<I, K, F>(streams: I, key_fn: F) -> MergeOrdered<I::Item, F>
+    where I: IntoIterator,
+          I::Item: Stream,
+          F: FnMut(&<I::Item as Stream>::Item) -> K,
+          K: Ord,
+{
+    MergeOrdered {
+        streams: streams.into_iter().map(|s| (s.fuse(), None)).collect(),
+        key_fn: key_fn,
+    }
+}
+
+impl<S, F, K> Stream for MergeOrdered<S, F>
+    where S: Stream,
+          F: FnMut(&S::Item) -> K,
+          K: Ord,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<S::Item>, S::Error> {
+        // Fill up the head of every stream which is still going. All of them
+        // are polled, even once one isn't ready, so that they're all waiting
+        // to wake us up.
+        let mut ready = true;
+        for entry in self.streams.iter_mut() {
+            if entry.1.is_none() {
+                match entry.0.poll()? {
+                    Async::Ready(item) => entry.1 = item,
+                    Async::NotReady => ready = false,
+                }
+            }
+        }
+        if !ready {
+            return Ok(Async::NotReady)
+        }
+
+        let mut min: Option<(usize, K)> = None;
+        for (i, entry) in self.streams.iter().enumerate() {
+            if let Some(ref item) = entry.1 {
+                let key = (self.key_fn)(item);
+                let smaller = match min {
+                    Some((_, ref min_key)) => key < *min_key,
+                    None => true,
+                };
+                if smaller {
+                    min = Some((i, key));
+                }
+            }
+        }
+
+        match min {
+            Some((i, _)) => Ok(Async::Ready(self.streams[i].1.take())),
+            None => Ok(Async::Ready(None)),
+        }
+    }
+}
+
+impl<S, F> fmt::Debug for MergeOrdered<S, F>
+    where S: Stream + fmt::Debug,
+          S::Item: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("MergeOrdered")
+            .field("streams", &self.streams)
+            .finish()
+    }
+}
@@ -15,7 +15,8 @@
 //!
 //! [online]: https://tokio.rs/docs/getting-started/streams-and-sinks/
 
-use {IntoFuture, Poll};
+use {Future, IntoFuture, Poll};
+use future::Either;
 
 mod iter;
 #[allow(deprecated)]
@@ -33,10 +34,17 @@ pub use self::repeat::{repeat, Repeat};
 
 mod and_then;
 mod chain;
+mod chain_lazy;
+mod chain_with;
+mod collect_into;
 mod concat;
+mod count;
 mod empty;
+mod enumerate;
 mod filter;
 mod filter_map;
+mod filter_map_async;
+mod flat_map;
 mod flatten;
 mod fold;
 mod for_each;
@@ -44,9 +52,12 @@ mod from_err;
 mod fuse;
 mod future;
 mod inspect;
+mod inspect_err;
+mod last;
 mod map;
 mod map_err;
 mod merge;
+mod on_complete;
 mod once;
 mod or_else;
 mod peek;
@@ -55,17 +66,30 @@ mod select;
 mod skip;
 mod skip_while;
 mod take;
+mod take_until;
 mod take_while;
+mod tee;
 mod then;
+mod try_flatten;
 mod unfold;
+mod until_error;
 mod zip;
+mod zip3;
+mod zip_latest;
 mod forward;
 pub use self::and_then::AndThen;
 pub use self::chain::Chain;
+pub use self::chain_lazy::ChainLazy;
+pub use self::chain_with::ChainWith;
+pub use self::collect_into::CollectInto;
 pub use self::concat::{Concat, Concat2};
+pub use self::count::Count;
 pub use self::empty::{Empty, empty};
+pub use self::enumerate::Enumerate;
 pub use self::filter::Filter;
 pub use self::filter_map::FilterMap;
+pub use self::filter_map_async::FilterMapAsync;
+pub use self::flat_map::FlatMap;
 pub use self::flatten::Flatten;
 pub use self::fold::Fold;
 pub use self::for_each::ForEach;
@@ -73,45 +97,91 @@ pub use self::from_err::FromErr;
 pub use self::fuse::Fuse;
 pub use self::future::StreamFuture;
 pub use self::inspect::Inspect;
+pub use self::inspect_err::InspectErr;
+pub use self::last::Last;
 pub use self::map::Map;
 pub use self::map_err::MapErr;
 #[allow(deprecated)]
 pub use self::merge::{Merge, MergedItem};
 pub use self::once::{Once, once};
+pub use self::on_complete::OnComplete;
 pub use self::or_else::OrElse;
 pub use self::peek::Peekable;
-pub use self::poll_fn::{poll_fn, PollFn};
+pub use self::poll_fn::{poll_fn, poll_fn_stateful, PollFn, PollFnStateful};
 pub use self::select::Select;
 pub use self::skip::Skip;
 pub use self::skip_while::SkipWhile;
 pub use self::take::Take;
+pub use self::take_until::TakeUntil;
 pub use self::take_while::TakeWhile;
+pub use self::tee::Tee;
 pub use self::then::Then;
-pub use self::unfold::{Unfold, unfold};
+pub use self::try_flatten::TryFlatten;
+pub use self::unfold::{Unfold, unfold, UnfoldResult, unfold_result};
+pub use self::unfold::unfold_result as try_unfold;
+pub use self::until_error::UntilError;
 pub use self::zip::Zip;
-pub use self::forward::Forward;
+pub use self::zip3::Zip3;
+pub use self::zip_latest::ZipLatest;
+pub use self::forward::{Forward, FlushPolicy};
 use sink::{Sink};
 
 if_std! {
     use std;
 
     mod buffered;
+    mod buffered_ordered;
     mod buffer_unordered;
+    mod buffer_hysteresis;
     mod catch_unwind;
     mod chunks;
     mod collect;
+    mod collect_until_err;
+    mod cycle;
+    mod debounce;
+    mod flatten_unordered;
+    mod for_each_concurrent;
+    mod group_by;
+    mod interval;
+    mod map_concurrent;
+    mod merge_ordered;
+    mod partition;
+    mod sample;
+    mod skip_for;
     mod wait;
+    mod windows;
+    mod zip_all;
     mod channel;
     mod split;
+    mod split_local;
+    mod try_buffered;
     pub mod futures_unordered;
     mod futures_ordered;
     pub use self::buffered::Buffered;
+    pub use self::buffered_ordered::BufferedOrdered;
     pub use self::buffer_unordered::BufferUnordered;
+    pub use self::buffer_hysteresis::BufferHysteresis;
     pub use self::catch_unwind::CatchUnwind;
     pub use self::chunks::Chunks;
     pub use self::collect::Collect;
+    pub use self::collect_until_err::CollectUntilErr;
+    pub use self::cycle::Cycle;
+    pub use self::debounce::Debounce;
+    pub use self::flatten_unordered::FlattenUnordered;
+    pub use self::for_each_concurrent::ForEachConcurrent;
+    pub use self::group_by::{GroupBy, GroupStream, GroupBuffering};
+    pub use self::interval::{interval, interval_with, Interval, MissedTicks};
+    pub use self::map_concurrent::MapConcurrent;
+    pub use self::merge_ordered::{merge_ordered, MergeOrdered};
+    pub use self::partition::{PartitionTrue, PartitionFalse};
+    pub use self::sample::Sample;
+    pub use self::skip_for::SkipFor;
     pub use self::wait::Wait;
+    pub use self::windows::Windows;
+    pub use self::zip_all::{zip_all, ZipAll};
     pub use self::split::{SplitStream, SplitSink};
+    pub use self::split_local::{LocalSplitStream, LocalSplitSink};
+    pub use self::try_buffered::TryBuffered;
     pub use self::futures_unordered::FuturesUnordered;
     pub use self::futures_ordered::{futures_ordered, FuturesOrdered};
 
@@ -127,6 +197,11 @@ if_std! {
                          details in https://github.com/alexcrichton/futures-rs/issues/228")]
     pub type BoxStream<T, E> = ::std::boxed::Box<Stream<Item = T, Error = E> + Send>;
 
+    /// A type alias for `Box<Stream>`, without a `Send` bound.
+    ///
+    /// This is the type returned by `Stream::boxed_local`.
+    pub type LocalBoxStream<T, E> = ::std::boxed::Box<Stream<Item = T, Error = E>>;
+
     impl<S: ?Sized + Stream> Stream for ::std::boxed::Box<S> {
         type Item = S::Item;
         type Error = S::Error;
@@ -275,6 +350,33 @@ pub trait Stream {
         ::std::boxed::Box::new(self)
     }
 
+    /// Convenience function for turning this stream into a trait object
+    /// which isn't required to be `Send`.
+    ///
+    /// This simply avoids the need to write `Box::new` and can often help with
+    /// type inference as well by always returning a trait object. Unlike
+    /// `Send` trait objects this can hold streams which must stay on the
+    /// thread which created them, for example because they contain an `Rc`.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::stream::*;
+    /// use futures::unsync::mpsc;
+    ///
+    /// let (_tx, rx) = mpsc::channel(1);
+    /// let a: LocalBoxStream<i32, ()> = rx.boxed_local();
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn boxed_local(self) -> LocalBoxStream<Self::Item, Self::Error>
+        where Self: Sized + 'static,
+    {
+        ::std::boxed::Box::new(self)
+    }
+
     /// Converts this stream into a `Future`.
     ///
     /// A stream can be viewed as a future which will resolve to a pair containing
@@ -408,6 +510,37 @@ pub trait Stream {
         filter_map::new(self, f)
     }
 
+    /// Filters the values produced by this stream while simultaneously mapping
+    /// them to a different type, with the decision made asynchronously.
+    ///
+    /// This is like `filter_map`, except that the closure returns a future
+    /// resolving to an `Option`. If it resolves to `Some`, the value inside is
+    /// yielded, and if it resolves to `None` the item is skipped. Only one
+    /// item is processed at a time, so the order of the stream is preserved.
+    ///
+    /// Errors from this stream or from the returned futures are passed
+    /// through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    /// use futures::future;
+    ///
+    /// let squares = stream::iter_ok::<_, ()>(1..7).filter_map_async(|x| {
+    ///     future::ok(if x % 2 == 0 { Some(x * x) } else { None })
+    /// });
+    /// assert_eq!(squares.collect().wait(), Ok(vec![4, 16, 36]));
+    /// ```
+    fn filter_map_async<F, R, B>(self, f: F) -> FilterMapAsync<Self, F, R>
+        where F: FnMut(Self::Item) -> R,
+              R: IntoFuture<Item = Option<B>, Error = Self::Error>,
+              Self: Sized
+    {
+        filter_map_async::new(self, f)
+    }
+
     /// Chain on a computation for when a value is ready, passing the resulting
     /// item to the provided closure `f`.
     ///
@@ -512,8 +645,24 @@ pub trait Stream {
     /// trait so it is possible to simply alter the `Result` yielded to the
     /// closure and return it.
     ///
+    /// After an error has been recovered from, the underlying stream keeps
+    /// being polled for more items, and every later error is passed to the
+    /// closure in the same way. If the returned future fails, its error is
+    /// yielded by this stream instead, which doesn't end it either.
+    ///
     /// Note that this function consumes the receiving stream and returns a
     /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    ///
+    /// let items = vec![Ok(1), Err("bad"), Ok(3), Err("worse")];
+    /// let recovered = stream::iter_result(items).or_else(|_| Ok::<_, ()>(0));
+    /// assert_eq!(recovered.collect().wait(), Ok(vec![1, 0, 3, 0]));
+    /// ```
     fn or_else<F, U>(self, f: F) -> OrElse<Self, F, U>
         where F: FnMut(Self::Error) -> U,
               U: IntoFuture<Item = Self::Item>,
@@ -561,6 +710,107 @@ pub trait Stream {
         collect::new(self)
     }
 
+    /// Collect the successful values of a stream of `Result`s into a vector,
+    /// stopping at the first failed one.
+    ///
+    /// The returned future resolves to the values collected, and, if one of
+    /// the items was an `Err`, its index in the stream along with its error.
+    /// Nothing after that item is pulled off the stream. This makes it easy
+    /// to resume processing a stream of fallible items from where it failed.
+    ///
+    /// If this stream itself returns an error, the values collected so far are
+    /// dropped and the error is returned.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    ///
+    /// let items = vec![Ok(1), Ok(2), Err("bad"), Ok(3)];
+    /// let result = stream::iter_ok::<_, ()>(items).collect_until_err().wait();
+    /// assert_eq!(result, Ok((vec![1, 2], Some((2, "bad")))));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn collect_until_err<T, E>(self) -> CollectUntilErr<Self, T>
+        where Self: Stream<Item = Result<T, E>> + Sized
+    {
+        collect_until_err::new(self)
+    }
+
+    /// Collect all of the values of this stream into the provided container.
+    ///
+    /// This is like `collect`, except that the items are added to `container`
+    /// with its `Extend` implementation, and `container` is what the returned
+    /// future resolves to. This can be used to reuse an already allocated
+    /// buffer, or to collect into something other than a `Vec`, such as a
+    /// `HashMap` from a stream of pairs.
+    ///
+    /// If an error happens then the container, along with all elements
+    /// collected so far, is dropped and the error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    ///
+    /// let mut buf = Vec::with_capacity(4);
+    /// buf.push(0);
+    /// let buf = stream::iter_ok::<_, ()>(1..4).collect_into(buf).wait().unwrap();
+    /// assert_eq!(buf, vec![0, 1, 2, 3]);
+    /// ```
+    fn collect_into<C>(self, container: C) -> CollectInto<Self, C>
+        where C: Extend<Self::Item>,
+              Self: Sized
+    {
+        collect_into::new(self, container)
+    }
+
+    /// Drains this stream, returning a future which resolves to the number of
+    /// items it produced.
+    ///
+    /// If an error happens then counting stops and the error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    ///
+    /// let count = stream::iter_ok::<_, ()>(1..6).count();
+    /// assert_eq!(count.wait(), Ok(5));
+    /// ```
+    fn count(self) -> Count<Self>
+        where Self: Sized
+    {
+        count::new(self)
+    }
+
+    /// Drains this stream, returning a future which resolves to the last item
+    /// it produced, or `None` if it was empty.
+    ///
+    /// If an error happens then the items seen so far are dropped and the
+    /// error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    ///
+    /// let last = stream::iter_ok::<_, ()>(1..6).last();
+    /// assert_eq!(last.wait(), Ok(Some(5)));
+    /// ```
+    fn last(self) -> Last<Self>
+        where Self: Sized
+    {
+        last::new(self)
+    }
+
     /// Concatenate all results of a stream into a single extendable
     /// destination, returning a future representing the end result.
     ///
@@ -710,6 +960,97 @@ pub trait Stream {
         flatten::new(self)
     }
 
+    /// Maps each item of this stream to a stream, and flattens those into one
+    /// stream, like `Iterator::flat_map`.
+    ///
+    /// This is the same as `map` followed by `flatten`: the stream returned by
+    /// `f` for an item is run to completion before the next item of this
+    /// stream is pulled out and mapped. Errors from this stream are converted
+    /// into the error type of the streams returned by `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    ///
+    /// let stream = stream::iter_ok::<_, ()>(1..4)
+    ///     .flat_map(|n| stream::iter_ok::<_, ()>(0..n));
+    /// assert_eq!(stream.collect().wait(), Ok(vec![0, 0, 1, 0, 1, 2]));
+    /// ```
+    fn flat_map<F, U>(self, f: F) -> FlatMap<Self, F, U>
+        where F: FnMut(Self::Item) -> U,
+              U: Stream,
+              U::Error: From<Self::Error>,
+              Self: Sized
+    {
+        flat_map::new(self, f)
+    }
+
+    /// Flattens a stream of results of streams into one stream, passing
+    /// through the errors.
+    ///
+    /// Like `flatten`, each inner stream is exhausted before moving on to the
+    /// next item of this stream. An `Err` item of this stream, which has the
+    /// same error type as the inner streams, is yielded as an error of the
+    /// returned stream, after which the next item is looked at.
+    /// Errors of this stream and of the inner streams are passed through as
+    /// well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    ///
+    /// let streams = vec![
+    ///     Ok(stream::iter_ok::<_, u32>(vec![1, 2])),
+    ///     Err(3),
+    ///     Ok(stream::iter_ok(vec![4])),
+    /// ];
+    /// let mut flat = stream::iter_ok::<_, u32>(streams).try_flatten().wait();
+    /// assert_eq!(flat.next(), Some(Ok(1)));
+    /// assert_eq!(flat.next(), Some(Ok(2)));
+    /// assert_eq!(flat.next(), Some(Err(3)));
+    /// assert_eq!(flat.next(), Some(Ok(4)));
+    /// assert_eq!(flat.next(), None);
+    /// ```
+    fn try_flatten<U>(self) -> TryFlatten<Self, U>
+        where Self: Stream<Item = Result<U, U::Error>> + Sized,
+              U: Stream,
+              U::Error: From<Self::Error>,
+    {
+        try_flatten::new(self)
+    }
+
+    /// Flattens a stream of streams into one stream, polling up to `amt`
+    /// of the inner streams concurrently.
+    ///
+    /// Unlike `flatten`, which exhausts each inner stream before moving on to
+    /// the next, this combinator keeps up to `amt` inner streams in flight and
+    /// yields their items in the order in which they become available. As
+    /// soon as an inner stream finishes another one is pulled from this
+    /// stream to take its place. The returned stream ends once this stream
+    /// and all of the inner streams are exhausted.
+    ///
+    /// Errors from this stream or from any of the inner streams are passed
+    /// through as soon as they occur.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `amt` is zero.
+    #[cfg(feature = "use_std")]
+    fn flatten_unordered(self, amt: usize) -> FlattenUnordered<Self>
+        where Self::Item: Stream,
+              <Self::Item as Stream>::Error: From<Self::Error>,
+              Self: Sized
+    {
+        flatten_unordered::new(self, amt)
+    }
+
     /// Skip elements on this stream while the predicate provided resolves to
     /// `true`.
     ///
@@ -717,6 +1058,12 @@ pub trait Stream {
     /// stream until the `predicate` resolves to `false`. Once one element
     /// returns false all future elements will be returned from the underlying
     /// stream.
+    ///
+    /// The predicate may return any future, so it can perform an asynchronous
+    /// check for each leading element. The stream doesn't advance while that
+    /// future is pending, and an error from it is passed through on this
+    /// stream. The predicate isn't called again once it has resolved to
+    /// `false`.
     fn skip_while<P, R>(self, pred: P) -> SkipWhile<Self, P, R>
         where P: FnMut(&Self::Item) -> R,
               R: IntoFuture<Item=bool, Error=Self::Error>,
@@ -739,6 +1086,65 @@ pub trait Stream {
         take_while::new(self, pred)
     }
 
+    /// Take elements from this stream until the provided future resolves.
+    ///
+    /// This function polls `signal` every time the returned stream is polled,
+    /// before polling this stream. As long as `signal` isn't ready the
+    /// elements of this stream are passed through. As soon as `signal`
+    /// resolves, whether successfully or with an error, the returned stream
+    /// ends, without polling this stream any further. This is useful to stop
+    /// a stream from the outside, for example when shutting down.
+    ///
+    /// The value or error `signal` resolved with doesn't appear on the stream,
+    /// but can be retrieved afterwards with `TakeUntil::take_result`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    /// use futures::sync::oneshot;
+    ///
+    /// let (stop, signal) = oneshot::channel::<()>();
+    /// let mut stop = Some(stop);
+    /// let mut n = 0;
+    /// let numbers = stream::repeat::<_, ()>(1).map(move |x| {
+    ///     n += x;
+    ///     if n == 3 {
+    ///         drop(stop.take().unwrap().send(()));
+    ///     }
+    ///     n
+    /// });
+    /// let collected = numbers.take_until(signal).collect().wait();
+    /// assert_eq!(collected, Ok(vec![1, 2, 3]));
+    /// ```
+    fn take_until<F>(self, signal: F) -> TakeUntil<Self, F>
+        where F: Future,
+              Self: Sized
+    {
+        take_until::new(self, signal)
+    }
+
+    /// Takes elements from this stream for the given `duration`, and then
+    /// ends it.
+    ///
+    /// The `timer` is used to wait for the duration, which starts when this
+    /// method is called. The stream ends as soon as the duration has elapsed,
+    /// even if it has more items ready, or before that if the underlying
+    /// stream ends. If the timer fails, the stream ends as well.
+    ///
+    /// This is a shorthand for `take_until` with a delay from the `timer`.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    #[cfg(feature = "use_std")]
+    fn take_for<T>(self, duration: std::time::Duration, timer: &T) -> TakeUntil<Self, T::Delay>
+        where T: ::timer::Timer,
+              Self: Sized
+    {
+        take_until::new(self, timer.delay(timer.now() + duration))
+    }
+
     /// Runs this stream to completion, executing the provided closure for each
     /// element on the stream.
     ///
@@ -761,6 +1167,36 @@ pub trait Stream {
         for_each::new(self, f)
     }
 
+    /// Runs this stream to completion, executing the provided closure for each
+    /// element on the stream, with up to `limit` of the resulting futures
+    /// running concurrently.
+    ///
+    /// This is like `for_each`, except that the future produced for an item
+    /// doesn't have to complete before the next item is taken off the stream.
+    /// Up to `limit` of these futures are run at once, and this stream is only
+    /// polled for more items while there are fewer than `limit` of them, so
+    /// backpressure still applies to it.
+    ///
+    /// The returned future resolves once this stream has ended and all of the
+    /// futures have completed. Any error on the stream or in one of the
+    /// futures causes the remaining futures to be dropped and the returned
+    /// future to resolve to that error.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `limit` is zero.
+    #[cfg(feature = "use_std")]
+    fn for_each_concurrent<F, U>(self, limit: usize, f: F) -> ForEachConcurrent<Self, F, U>
+        where F: FnMut(Self::Item) -> U,
+              U: IntoFuture<Item = (), Error = Self::Error>,
+              Self: Sized
+    {
+        for_each_concurrent::new(self, limit, f)
+    }
+
     /// Map this stream's error to any error implementing `From` for
     /// this stream's `Error`, returning a new stream.
     ///
@@ -794,6 +1230,29 @@ pub trait Stream {
         take::new(self, amt)
     }
 
+    /// Creates a new stream which pairs each item of the underlying stream
+    /// with its index, starting at 0.
+    ///
+    /// # Errors
+    ///
+    /// All errors yielded from underlying stream are passed through and do not
+    /// advance the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    ///
+    /// let items = stream::iter_ok::<_, ()>(vec!['a', 'b', 'c']).enumerate();
+    /// assert_eq!(items.collect().wait(), Ok(vec![(0, 'a'), (1, 'b'), (2, 'c')]));
+    /// ```
+    fn enumerate(self) -> Enumerate<Self>
+        where Self: Sized
+    {
+        enumerate::new(self)
+    }
+
     /// Creates a new stream which skips `amt` items of the underlying stream.
     ///
     /// Once `amt` items have been skipped from this stream then it will always
@@ -809,6 +1268,29 @@ pub trait Stream {
         skip::new(self, amt)
     }
 
+    /// Creates a new stream which skips the items of the underlying stream
+    /// for the given `duration`.
+    ///
+    /// The `timer` is used to wait for the duration, which starts when this
+    /// method is called. Items which arrive before the duration has elapsed
+    /// are dropped, and all later ones are passed through. If the timer
+    /// fails, the duration is taken to have elapsed.
+    ///
+    /// # Errors
+    ///
+    /// All errors yielded from underlying stream are passed through, even
+    /// while items are being skipped.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    #[cfg(feature = "use_std")]
+    fn skip_for<T>(self, duration: std::time::Duration, timer: &T) -> SkipFor<Self, T::Delay>
+        where T: ::timer::Timer,
+              Self: Sized
+    {
+        skip_for::new(self, duration, timer)
+    }
+
     /// Fuse a stream such that `poll` will never again be called once it has
     /// finished.
     ///
@@ -830,6 +1312,45 @@ pub trait Stream {
         fuse::new(self)
     }
 
+    /// Wraps this stream in the `A` variant of an `Either`.
+    ///
+    /// This is a shorthand for `Either::A(stream)`, useful when the branches
+    /// of an `if` or `match` return different types of streams with the same
+    /// item and error types, which need to be unified into a single type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    ///
+    /// let x = 6;
+    /// let stream = if x < 10 {
+    ///     stream::iter_ok::<_, ()>(vec![x]).left_stream()
+    /// } else {
+    ///     stream::empty().right_stream()
+    /// };
+    ///
+    /// assert_eq!(stream.collect().wait(), Ok(vec![x]));
+    /// ```
+    fn left_stream<B>(self) -> Either<Self, B>
+        where B: Stream<Item = Self::Item, Error = Self::Error>,
+              Self: Sized
+    {
+        Either::A(self)
+    }
+
+    /// Wraps this stream in the `B` variant of an `Either`.
+    ///
+    /// This is a shorthand for `Either::B(stream)`, see `left_stream` for
+    /// more details.
+    fn right_stream<A>(self) -> Either<A, Self>
+        where A: Stream<Item = Self::Item, Error = Self::Error>,
+              Self: Sized
+    {
+        Either::B(self)
+    }
+
     /// Borrows a stream, rather than consuming it.
     ///
     /// This is useful to allow applying stream adaptors while still retaining
@@ -916,39 +1437,127 @@ pub trait Stream {
         buffered::new(self, amt)
     }
 
-    /// An adaptor for creating a buffered list of pending futures (unordered).
+    /// An adaptor for creating a buffered list of pending futures which
+    /// returns results in order, but lets a few futures complete ahead of a
+    /// slow one.
     ///
-    /// If this stream's item can be converted into a future, then this adaptor
-    /// will buffer up to `amt` futures and then return results in the order
-    /// in which they complete. No more than `amt` futures will be buffered at
-    /// any point in time, and less than `amt` may also be buffered depending on
-    /// the state of each future.
+    /// Like `buffered`, this runs up to `concurrency` futures at once and
+    /// returns their results in the same order as the underlying stream.
+    /// With `buffered` though, futures which complete while an earlier one is
+    /// still pending keep occupying their slot, so a single slow future stalls
+    /// the whole pipeline. Here such results are instead moved into a reorder
+    /// buffer, freeing their slot for the next future. New futures are only
+    /// held back once `concurrency + reorder_window` of them are outstanding,
+    /// counting from the oldest one whose result hasn't been returned yet.
+    ///
+    /// With a `reorder_window` of 0 this behaves exactly like `buffered`.
     ///
     /// The returned stream will be a stream of each future's result, with
-    /// errors passed through whenever they occur.
+    /// errors returned in order along with the other results.
     ///
     /// This method is only available when the `use_std` feature of this
     /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `concurrency` is zero.
     #[cfg(feature = "use_std")]
-    fn buffer_unordered(self, amt: usize) -> BufferUnordered<Self>
+    fn buffered_ordered(self, concurrency: usize, reorder_window: usize)
+        -> BufferedOrdered<Self>
         where Self::Item: IntoFuture<Error = <Self as Stream>::Error>,
               Self: Sized
     {
-        buffer_unordered::new(self, amt)
+        buffered_ordered::new(self, concurrency, reorder_window)
     }
 
-    /// An adapter for merging the output of two streams.
+    /// An adaptor for creating a buffered list of pending futures which stops
+    /// at the first error.
     ///
-    /// The merged stream produces items from one or both of the underlying
-    /// streams as they become available. Errors, however, are not merged: you
-    /// get at most one error at a time.
-    #[deprecated(note = "functionality provided by `select` now")]
-    #[allow(deprecated)]
-    fn merge<S>(self, other: S) -> Merge<Self, S>
-        where S: Stream<Error = Self::Error>,
-              Self: Sized,
-    {
-        merge::new(self, other)
+    /// This behaves like `buffered`, running up to `amt` futures at once and
+    /// returning their results in the same order as the underlying stream,
+    /// except for how errors are handled. As soon as the underlying stream or
+    /// any of the futures fails, all other pending futures are dropped, the
+    /// error is returned, and the stream ends. Results of futures which
+    /// completed before the error but weren't yet yielded are discarded.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    #[cfg(feature = "use_std")]
+    fn try_buffered(self, amt: usize) -> TryBuffered<Self>
+        where Self::Item: IntoFuture<Error = <Self as Stream>::Error>,
+              Self: Sized
+    {
+        try_buffered::new(self, amt)
+    }
+
+    /// Maps each item of this stream to a future, running up to `concurrency`
+    /// of those futures at once and returning their results in the same order
+    /// as the underlying stream.
+    ///
+    /// This is the same as `self.map(f).try_buffered(concurrency)`: as soon as
+    /// the underlying stream or any of the futures fails, all other pending
+    /// futures are dropped, the error is returned, and the stream ends.
+    /// Results of futures which completed before the error but weren't yet
+    /// yielded are discarded as well, so an error from the second item may
+    /// be returned without the result of the first one ever being yielded.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    /// use futures::future;
+    ///
+    /// let doubled = stream::iter_ok::<_, ()>(vec![1, 2, 3])
+    ///     .map_concurrent(2, |x| future::ok(x * 2));
+    /// assert_eq!(doubled.collect().wait(), Ok(vec![2, 4, 6]));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn map_concurrent<F, U>(self, concurrency: usize, f: F)
+        -> MapConcurrent<Self, F>
+        where F: FnMut(Self::Item) -> U,
+              U: IntoFuture<Error = Self::Error>,
+              Self: Sized
+    {
+        map_concurrent::new(self, concurrency, f)
+    }
+
+    /// An adaptor for creating a buffered list of pending futures (unordered).
+    ///
+    /// If this stream's item can be converted into a future, then this adaptor
+    /// will buffer up to `amt` futures and then return results in the order
+    /// in which they complete. No more than `amt` futures will be buffered at
+    /// any point in time, and less than `amt` may also be buffered depending on
+    /// the state of each future.
+    ///
+    /// The returned stream will be a stream of each future's result, with
+    /// errors passed through whenever they occur.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    #[cfg(feature = "use_std")]
+    fn buffer_unordered(self, amt: usize) -> BufferUnordered<Self>
+        where Self::Item: IntoFuture<Error = <Self as Stream>::Error>,
+              Self: Sized
+    {
+        buffer_unordered::new(self, amt)
+    }
+
+    /// An adapter for merging the output of two streams.
+    ///
+    /// The merged stream produces items from one or both of the underlying
+    /// streams as they become available. Errors, however, are not merged: you
+    /// get at most one error at a time.
+    #[deprecated(note = "functionality provided by `select` now")]
+    #[allow(deprecated)]
+    fn merge<S>(self, other: S) -> Merge<Self, S>
+        where S: Stream<Error = Self::Error>,
+              Self: Sized,
+    {
+        merge::new(self, other)
     }
 
     /// An adapter for zipping two streams together.
@@ -963,6 +1572,111 @@ pub trait Stream {
         zip::new(self, other)
     }
 
+    /// An adapter for zipping three streams together.
+    ///
+    /// This is like `zip`, except that the zipped stream waits for all three
+    /// streams to produce an item and returns them as a triple, rather than
+    /// needing nested pairs. If an error happens, then that error will be
+    /// returned immediately. If any of the streams ends then the zipped stream
+    /// will also end.
+    ///
+    /// To zip together a number of streams only known at runtime, see the
+    /// `stream::zip_all` function.
+    fn zip3<S2, S3>(self, stream2: S2, stream3: S3) -> Zip3<Self, S2, S3>
+        where S2: Stream<Error = Self::Error>,
+              S3: Stream<Error = Self::Error>,
+              Self: Sized,
+    {
+        zip3::new(self, stream2, stream3)
+    }
+
+    /// An adapter for combining the most recent items of two streams.
+    ///
+    /// Whenever either stream produces an item the combined stream yields a
+    /// pair of that item and the most recent item of the other stream, which
+    /// is cloned and reused until the other stream produces something new.
+    /// Nothing is yielded until both streams have produced at least one item.
+    ///
+    /// Errors from either stream are passed through immediately. The combined
+    /// stream ends once both streams have ended, or as soon as either stream
+    /// ends without ever having produced an item.
+    fn zip_latest<S>(self, other: S) -> ZipLatest<Self, S>
+        where S: Stream<Error = Self::Error>,
+              Self::Item: Clone,
+              S::Item: Clone,
+              Self: Sized,
+    {
+        zip_latest::new(self, other)
+    }
+
+    /// Downsamples this stream, yielding its most recent item once every
+    /// `interval`.
+    ///
+    /// The ticks are those of `stream::interval` with the given `timer`. This
+    /// stream is continuously drained, and of all the items it produces
+    /// between two ticks only the last one is yielded, the others are
+    /// dropped. If no new item arrived since the previous tick then nothing is
+    /// yielded for that tick.
+    ///
+    /// Errors from this stream are passed through immediately. The sampled
+    /// stream ends once this stream has ended and its last item has been
+    /// yielded, or if the timer fails.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `interval` is zero.
+    #[cfg(feature = "use_std")]
+    fn sample<T>(self, interval: std::time::Duration, timer: T) -> Sample<Self, T>
+        where T: ::timer::Timer,
+              Self: Sized,
+    {
+        sample::new(self, interval, timer)
+    }
+
+    /// Debounces this stream, yielding an item only once no newer item has
+    /// arrived for the `quiet` duration.
+    ///
+    /// Every item restarts the quiet period and replaces the previously
+    /// remembered one, so of a burst of items only the last is yielded, once
+    /// the burst is over. The `timer` is used to wait for the quiet period,
+    /// and if it fails the quiet period is taken to have elapsed.
+    ///
+    /// Errors from the stream are passed through immediately. When this
+    /// stream ends, the last item of a pending burst is yielded right away
+    /// rather than waiting for the quiet period.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    #[cfg(feature = "use_std")]
+    fn debounce<T>(self, quiet: std::time::Duration, timer: T) -> Debounce<Self, T>
+        where T: ::timer::Timer,
+              Self: Sized,
+    {
+        debounce::new(self, quiet, timer, false)
+    }
+
+    /// Debounces this stream on the leading edge, yielding the first item of
+    /// every burst immediately and dropping the rest of it.
+    ///
+    /// This is the counterpart of `debounce`: a burst lasts until no item has
+    /// arrived for the `quiet` duration, with every item restarting the quiet
+    /// period through `timer` in the same way, but it's the first item of a
+    /// burst which is yielded rather than the last one.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    #[cfg(feature = "use_std")]
+    fn debounce_leading<T>(self, quiet: std::time::Duration, timer: T)
+        -> Debounce<Self, T>
+        where T: ::timer::Timer,
+              Self: Sized,
+    {
+        debounce::new(self, quiet, timer, true)
+    }
+
     /// Adapter for chaining two stream.
     ///
     /// The resulting stream emits elements from the first stream, and when
@@ -989,9 +1703,62 @@ pub trait Stream {
         chain::new(self, other)
     }
 
+    /// Adapter for chaining this stream with a stream which is only
+    /// constructed once this one has ended.
+    ///
+    /// This is like `chain`, except that the second stream is created by
+    /// calling `f` when this stream reaches its end, which helps when building
+    /// it is expensive or may not be needed at all. Unlike `chain`, an error
+    /// from this stream ends the resulting stream, after passing the error
+    /// through, without ever calling `f`.
+    ///
+    /// ```rust
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    ///
+    /// let chain = stream::iter_ok::<_, ()>(vec![1, 2])
+    ///     .chain_lazy(|| stream::iter_ok(vec![3]));
+    /// assert_eq!(chain.collect().wait(), Ok(vec![1, 2, 3]));
+    /// ```
+    fn chain_lazy<F, S>(self, f: F) -> ChainLazy<Self, S, F>
+        where F: FnOnce() -> S,
+              S: Stream<Item = Self::Item, Error = Self::Error>,
+              Self: Sized
+    {
+        chain_lazy::new(self, f)
+    }
+
+    /// Adapter for chaining this stream with a stream constructed from its
+    /// last item.
+    ///
+    /// This is like `chain_lazy`, except that `f` is also passed a clone of
+    /// the last item of this stream, or `None` if it had no items at all. This
+    /// is useful for following up on a stream with a continuation, such as
+    /// the next page of results after the last item of the current page. An
+    /// error from this stream ends the resulting stream, after passing the
+    /// error through, without ever calling `f`.
+    ///
+    /// ```rust
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    ///
+    /// let chain = stream::iter_ok::<_, ()>(vec![1, 2])
+    ///     .chain_with(|last| stream::iter_ok(last.map(|x| x + 1)));
+    /// assert_eq!(chain.collect().wait(), Ok(vec![1, 2, 3]));
+    /// ```
+    fn chain_with<F, S>(self, f: F) -> ChainWith<Self, S, F>
+        where F: FnOnce(Option<Self::Item>) -> S,
+              S: Stream<Item = Self::Item, Error = Self::Error>,
+              Self::Item: Clone,
+              Self: Sized
+    {
+        chain_with::new(self, f)
+    }
+
     /// Creates a new stream which exposes a `peek` method.
     ///
-    /// Calling `peek` returns a reference to the next item in the stream.
+    /// Calling `peek` returns a reference to the next item in the stream, and
+    /// `push_back` can be used to return an item to the front of the stream.
     fn peekable(self) -> Peekable<Self>
         where Self: Sized
     {
@@ -1025,6 +1792,117 @@ pub trait Stream {
         chunks::new(self, capacity)
     }
 
+    /// Repeats the items of this stream endlessly.
+    ///
+    /// The items are yielded as they come during the first pass through this
+    /// stream, and a clone of each of them is kept in a buffer. Once this
+    /// stream ends, the buffered items are yielded over and over again. Note
+    /// that this means all the items of this stream are kept in memory, so
+    /// it should only be used on streams which are known to be short. Use
+    /// `cycle_n` to bound the number of repetitions.
+    ///
+    /// If this stream ends without producing any item then so does the
+    /// returned stream. Errors are passed through during the first pass, and
+    /// aren't repeated.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    ///
+    /// let abc = stream::iter_ok::<_, ()>(vec!['a', 'b', 'c']).cycle();
+    /// assert_eq!(abc.take(5).collect().wait(), Ok(vec!['a', 'b', 'c', 'a', 'b']));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn cycle(self) -> Cycle<Self>
+        where Self::Item: Clone,
+              Self: Sized
+    {
+        cycle::new(self, None)
+    }
+
+    /// Repeats the items of this stream, `count` times in total.
+    ///
+    /// This is like `cycle`, except that the returned stream ends after going
+    /// through all the items `count` times. The first of these passes goes
+    /// through this stream itself, so `cycle_n(1)` yields the items of this
+    /// stream once and `cycle_n(0)` yields nothing.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    #[cfg(feature = "use_std")]
+    fn cycle_n(self, count: usize) -> Cycle<Self>
+        where Self::Item: Clone,
+              Self: Sized
+    {
+        cycle::new(self, Some(count))
+    }
+
+    /// An adaptor for yielding overlapping windows of the most recent `size`
+    /// items of the stream.
+    ///
+    /// Each item pulled from this stream, once at least `size` items have
+    /// been, causes a vector of the last `size` items to be yielded, the
+    /// window sliding along by one item at a time. Items are cloned into every
+    /// window they're part of. Nothing is yielded for a stream which ends
+    /// with fewer than `size` items, and no partial windows are yielded when
+    /// the stream ends.
+    ///
+    /// Errors are passed through the stream and don't affect the window.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    ///
+    /// let windows = stream::iter_ok::<_, ()>(vec![1, 2, 3, 4]).windows(2);
+    /// assert_eq!(windows.collect().wait(), Ok(vec![vec![1, 2], vec![2, 3], vec![3, 4]]));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn windows(self, size: usize) -> Windows<Self>
+        where Self::Item: Clone,
+              Self: Sized
+    {
+        windows::new(self, size)
+    }
+
+    /// An adaptor for reading ahead of the consumer of this stream into a
+    /// buffer, with hysteresis between two watermarks.
+    ///
+    /// Items are pulled out of this stream into a buffer as soon as they're
+    /// available, until `high` items are buffered. This stream then isn't
+    /// polled anymore until the consumer has taken enough items for the
+    /// buffer to drain to `low` items or fewer, after which it's read from
+    /// again. Compared to stopping and resuming at a single limit this avoids
+    /// toggling with every item when the consumer hovers around the limit.
+    ///
+    /// Errors are passed through immediately, ahead of any buffered items.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `low` isn't smaller than `high`.
+    #[cfg(feature = "use_std")]
+    fn buffer_hysteresis(self, high: usize, low: usize) -> BufferHysteresis<Self>
+        where Self: Sized
+    {
+        buffer_hysteresis::new(self, high, low)
+    }
+
     /// Creates a stream that selects the next element from either this stream
     /// or the provided one, whichever is ready first.
     ///
@@ -1065,6 +1943,26 @@ pub trait Stream {
         forward::new(self, sink)
     }
 
+    /// A future that completes after the given stream has been fully processed
+    /// into the sink, flushing the sink as specified by `policy`.
+    ///
+    /// This is like `forward`, which always uses
+    /// `FlushPolicy::WhenSourcePending`, except that how often the sink is
+    /// flushed while items are being sent can be tuned. Once the stream is
+    /// exhausted the sink is flushed and closed regardless of the policy, and
+    /// the pair `(stream, sink)` is returned.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `policy` is `FlushPolicy::EveryN(0)`.
+    fn forward_with<S>(self, sink: S, policy: FlushPolicy) -> Forward<Self, S>
+        where S: Sink<SinkItem = Self::Item>,
+              Self::Error: From<S::SinkError>,
+              Self: Sized
+    {
+        forward::with_policy(self, sink, policy)
+    }
+
     /// Splits this `Stream + Sink` object into separate `Stream` and `Sink`
     /// objects.
     ///
@@ -1081,6 +1979,24 @@ pub trait Stream {
         split::split(self)
     }
 
+    /// Splits this `Stream + Sink` object into separate `Stream` and `Sink`
+    /// objects which share it without any synchronization.
+    ///
+    /// This is like `split`, but the halves share the object through an
+    /// `Rc<RefCell<_>>` rather than a `BiLock`, avoiding atomic operations.
+    /// Neither half can be sent to another thread, so this is meant for
+    /// objects which aren't `Send` to begin with, or whose halves are driven
+    /// by tasks on the same thread.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    #[cfg(feature = "use_std")]
+    fn split_local(self) -> (LocalSplitSink<Self>, LocalSplitStream<Self>)
+        where Self: super::sink::Sink + Sized
+    {
+        split_local::split_local(self)
+    }
+
     /// Do something with each item of this stream, afterwards passing it on.
     ///
     /// This is similar to the `Iterator::inspect` method in the standard
@@ -1092,6 +2008,206 @@ pub trait Stream {
     {
         inspect::new(self, f)
     }
+
+    /// Do something with each error of this stream, afterwards passing it on.
+    ///
+    /// This is the counterpart of `inspect` for errors: `f` is called with a
+    /// reference to each error of this stream, which is then passed on
+    /// unchanged. Items are passed through without calling `f`.
+    fn inspect_err<F>(self, f: F) -> InspectErr<Self, F>
+        where F: FnMut(&Self::Error),
+              Self: Sized,
+    {
+        inspect_err::new(self, f)
+    }
+
+    /// Sends a clone of each item of this stream to the `side` sink before
+    /// yielding it.
+    ///
+    /// This is like `inspect`, except that items are observed by a sink. An
+    /// item is only yielded once `side` has accepted its clone, so a sink
+    /// which isn't ready to take more items holds this stream back. The sink
+    /// is flushed whenever this stream has no item ready, and closed once
+    /// this stream has ended, before the end is yielded.
+    ///
+    /// Errors from this stream are passed through, and errors from `side` are
+    /// converted into this stream's error type and yielded as well.
+    fn tee<K>(self, side: K) -> Tee<Self, K>
+        where K: Sink<SinkItem = Self::Item>,
+              Self::Item: Clone,
+              Self::Error: From<K::SinkError>,
+              Self: Sized,
+    {
+        tee::new(self, side)
+    }
+
+    /// Ends this stream at its first error, keeping the error aside rather
+    /// than yielding it.
+    ///
+    /// The returned stream yields the items of this stream, and ends cleanly
+    /// as soon as this stream either ends or returns an error. Such an error
+    /// can then be retrieved with `UntilError::error` or
+    /// `UntilError::into_error`. The returned stream never returns an error
+    /// itself, which helps when the combinators it feeds into can't deal with
+    /// errors of this stream's type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    ///
+    /// let items = vec![Ok(1), Ok(2), Err("bad"), Ok(3)];
+    /// let mut stream = stream::iter_result(items).until_error();
+    ///
+    /// let collected = (&mut stream).collect().wait();
+    /// assert_eq!(collected, Ok(vec![1, 2]));
+    /// assert_eq!(stream.into_error(), Some("bad"));
+    /// ```
+    fn until_error(self) -> UntilError<Self>
+        where Self: Sized,
+    {
+        until_error::new(self)
+    }
+
+    /// Runs a closure once this stream is finished with.
+    ///
+    /// The closure `f` is called exactly once, as soon as the first of these
+    /// happens: this stream ends, this stream returns an error, or the
+    /// returned stream is dropped. The latter covers streams which are
+    /// dropped before they end, for example by a `take` which has yielded
+    /// all of its items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    ///
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    ///
+    /// let done = Cell::new(false);
+    /// let items = stream::iter_ok::<_, ()>(vec![1, 2, 3])
+    ///     .on_complete(|| done.set(true))
+    ///     .take(2)
+    ///     .collect()
+    ///     .wait();
+    /// assert_eq!(items, Ok(vec![1, 2]));
+    /// assert!(done.get());
+    /// ```
+    fn on_complete<F>(self, f: F) -> OnComplete<Self, F>
+        where F: FnOnce(),
+              Self: Sized,
+    {
+        on_complete::new(self, f)
+    }
+
+    /// Splits this stream into sub-streams of the items which share a key.
+    ///
+    /// The closure `f` is called with each item of this stream to compute its
+    /// key. The first time a key is seen the returned stream yields that key
+    /// along with a `GroupStream`, and that item along with all later items
+    /// with the same key are delivered through that `GroupStream`. Each group
+    /// can be consumed independently, for example on separate tasks.
+    ///
+    /// The underlying stream is driven by whichever of the returned streams
+    /// is polled, so groups keep receiving items even if the outer stream is
+    /// no longer being polled. Errors from the underlying stream are returned
+    /// from whichever of these streams happened to pull them out. Once the
+    /// underlying stream ends, each group ends after its buffered items have
+    /// been taken.
+    ///
+    /// If a `GroupStream` is dropped then later items with its key start a
+    /// new group, and if the outer stream is dropped then items with new keys
+    /// are discarded.
+    ///
+    /// By default each group buffers items without bound, which can be
+    /// changed with `GroupBy::buffering`.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    ///
+    /// let groups = stream::iter_ok::<_, ()>(vec![1, 2, 3, 4, 5])
+    ///     .group_by(|x| x % 2)
+    ///     .collect()
+    ///     .wait()
+    ///     .unwrap();
+    /// let (key, odd) = groups.into_iter().next().unwrap();
+    /// assert_eq!(key, 1);
+    /// assert_eq!(odd.collect().wait(), Ok(vec![1, 3, 5]));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn group_by<K, F>(self, f: F) -> GroupBy<Self, K, F>
+        where K: std::hash::Hash + Eq + Clone,
+              F: FnMut(&Self::Item) -> K,
+              Self: Sized
+    {
+        group_by::new(self, f)
+    }
+
+    /// Splits this stream into two streams, of the items which match the
+    /// predicate `f` and of those which don't.
+    ///
+    /// Both streams can be consumed independently, each pulling items out of
+    /// this stream as needed. Items for the other stream which are pulled out
+    /// along the way are buffered until that stream takes them, without
+    /// bound. See `partition_bounded` to limit how many items are buffered.
+    /// If one of the two streams is dropped then its items are discarded.
+    ///
+    /// The two streams share this stream through an `Rc<RefCell<_>>`, so
+    /// they can't be sent to other threads, but they can be driven by tasks
+    /// on the same thread. Errors from this stream are returned from
+    /// whichever of the two streams happened to pull them out.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::prelude::*;
+    /// use futures::stream;
+    ///
+    /// let (evens, odds) = stream::iter_ok::<_, ()>(1..7).partition(|x| x % 2 == 0);
+    /// assert_eq!(odds.collect().wait(), Ok(vec![1, 3, 5]));
+    /// assert_eq!(evens.collect().wait(), Ok(vec![2, 4, 6]));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn partition<F>(self, f: F) -> (PartitionTrue<Self, F>, PartitionFalse<Self, F>)
+        where F: FnMut(&Self::Item) -> bool,
+              Self: Sized
+    {
+        partition::new(self, f, None)
+    }
+
+    /// Splits this stream into two streams, like `partition`, buffering at
+    /// most `capacity` items for each of them.
+    ///
+    /// Once an item is pulled out for a stream whose buffer is full, this
+    /// stream is no longer polled, by either of the two streams, until that
+    /// stream has taken an item. A stream which falls behind therefore holds
+    /// back the other one, rather than letting its buffer grow.
+    ///
+    /// This method is only available when the `use_std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    #[cfg(feature = "use_std")]
+    fn partition_bounded<F>(self, f: F, capacity: usize)
+        -> (PartitionTrue<Self, F>, PartitionFalse<Self, F>)
+        where F: FnMut(&Self::Item) -> bool,
+              Self: Sized
+    {
+        partition::new(self, f, Some(capacity))
+    }
 }
 
 impl<'a, S: ?Sized + Stream> Stream for &'a mut S {
@@ -0,0 +1,99 @@
+use {Async, Poll};
+use stream::Stream;
+
+/// A stream combinator which runs a closure once the underlying stream is
+/// finished with.
+///
+/// This structure is produced by the `Stream::on_complete` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct OnComplete<S, F> where F: FnOnce() {
+    stream: S,
+    f: Option<F>,
+}
+
+pub fn new<S, F>(stream: S, f: F) -> OnComplete<S, F>
+    where S: Stream,
+          F: FnOnce(),
+{
+    OnComplete {
+        stream: stream,
+        f: Some(f),
+    }
+}
+
+impl<S, F> OnComplete<S, F> where F: FnOnce() {
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &S {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.stream
+    }
+
+    fn complete(&mut self) {
+        if let Some(f) = self.f.take() {
+            f();
+        }
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, F> ::sink::Sink for OnComplete<S, F>
+    where S: ::sink::Sink + Stream,
+          F: FnOnce(),
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem) -> ::StartSend<S::SinkItem, S::SinkError> {
+        self.stream.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
+}
+
+impl<S, F> Stream for OnComplete<S, F>
+    where S: Stream,
+          F: FnOnce(),
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<S::Item>, S::Error> {
+        match self.stream.poll() {
+            Ok(Async::Ready(None)) => {
+                self.complete();
+                Ok(Async::Ready(None))
+            }
+            Err(e) => {
+                self.complete();
+                Err(e)
+            }
+            other => other,
+        }
+    }
+}
+
+impl<S, F> Drop for OnComplete<S, F> where F: FnOnce() {
+    fn drop(&mut self) {
+        self.complete();
+    }
+}
@@ -45,6 +45,10 @@ impl<S, F, U> ::sink::Sink for OrElse<S, F, U>
     fn close(&mut self) -> Poll<(), S::SinkError> {
         self.stream.close()
     }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
 }
 
 impl<S, F, U> Stream for OrElse<S, F, U>
@@ -0,0 +1,218 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::rc::Rc;
+
+use {Async, Poll};
+use stream::Stream;
+use task::{self, Task};
+
+/// The stream of items matching the predicate of `Stream::partition`.
+///
+/// This is one of the two streams created by the `Stream::partition` and
+/// `Stream::partition_bounded` methods.
+#[must_use = "streams do nothing unless polled"]
+pub struct PartitionTrue<S: Stream, F> {
+    shared: Rc<RefCell<Shared<S, F>>>,
+}
+
+/// The stream of items not matching the predicate of `Stream::partition`.
+///
+/// This is one of the two streams created by the `Stream::partition` and
+/// `Stream::partition_bounded` methods.
+#[must_use = "streams do nothing unless polled"]
+pub struct PartitionFalse<S: Stream, F> {
+    shared: Rc<RefCell<Shared<S, F>>>,
+}
+
+const TRUE: usize = 0;
+const FALSE: usize = 1;
+
+struct Shared<S: Stream, F> {
+    stream: S,
+    done: bool,
+    f: F,
+    capacity: Option<usize>,
+
+    /// Items pulled out of the stream but not yet taken, for each side.
+    buffers: [VecDeque<S::Item>; 2],
+
+    /// An item for a side whose buffer is full, holding back the stream.
+    blocked: Option<(usize, S::Item)>,
+
+    /// The task of each side waiting for items, if any.
+    tasks: [Option<Task>; 2],
+
+    alive: [bool; 2],
+}
+
+pub fn new<S, F>(s: S, f: F, capacity: Option<usize>)
+    -> (PartitionTrue<S, F>, PartitionFalse<S, F>)
+    where S: Stream,
+          F: FnMut(&S::Item) -> bool,
+{
+    if capacity == Some(0) {
+        panic!("partition capacity cannot be zero");
+    }
+    let shared = Rc::new(RefCell::new(Shared {
+        stream: s,
+        done: false,
+        f: f,
+        capacity: capacity,
+        buffers: [VecDeque::new(), VecDeque::new()],
+        blocked: None,
+        tasks: [None, None],
+        alive: [true, true],
+    }));
+    let t = PartitionTrue { shared: shared.clone() };
+    let f = PartitionFalse { shared: shared };
+    (t, f)
+}
+
+impl<S, F> Stream for PartitionTrue<S, F>
+    where S: Stream,
+          F: FnMut(&S::Item) -> bool,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<S::Item>, S::Error> {
+        self.shared.borrow_mut().poll_side(TRUE)
+    }
+}
+
+impl<S, F> Stream for PartitionFalse<S, F>
+    where S: Stream,
+          F: FnMut(&S::Item) -> bool,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<S::Item>, S::Error> {
+        self.shared.borrow_mut().poll_side(FALSE)
+    }
+}
+
+impl<S: Stream, F> Drop for PartitionTrue<S, F> {
+    fn drop(&mut self) {
+        if let Ok(mut shared) = self.shared.try_borrow_mut() {
+            shared.close_side(TRUE);
+        }
+    }
+}
+
+impl<S: Stream, F> Drop for PartitionFalse<S, F> {
+    fn drop(&mut self) {
+        if let Ok(mut shared) = self.shared.try_borrow_mut() {
+            shared.close_side(FALSE);
+        }
+    }
+}
+
+impl<S, F> Shared<S, F>
+    where S: Stream,
+          F: FnMut(&S::Item) -> bool,
+{
+    fn poll_side(&mut self, me: usize) -> Poll<Option<S::Item>, S::Error> {
+        let other = 1 - me;
+        loop {
+            if let Some(item) = self.buffers[me].pop_front() {
+                // If the stream was held back by this side being full then
+                // there's room again, and the other side may make progress.
+                let unblocked = match self.blocked {
+                    Some((side, _)) => side == me,
+                    None => false,
+                };
+                if unblocked {
+                    let (_, blocked) = self.blocked.take().unwrap();
+                    self.buffers[me].push_back(blocked);
+                    self.wake(other);
+                }
+                return Ok(Async::Ready(Some(item)))
+            }
+            if self.done {
+                return Ok(Async::Ready(None))
+            }
+            if self.blocked.is_some() {
+                self.tasks[me] = Some(task::current());
+                return Ok(Async::NotReady)
+            }
+
+            // Whichever side polls the stream last is the one woken up for
+            // its next item, so make sure the other side hears about items
+            // routed to it.
+            match self.stream.poll() {
+                Ok(Async::Ready(Some(item))) => {
+                    let side = if (self.f)(&item) { TRUE } else { FALSE };
+                    if side == me {
+                        return Ok(Async::Ready(Some(item)))
+                    }
+                    if !self.alive[side] {
+                        continue
+                    }
+                    if self.is_full(side) {
+                        self.blocked = Some((side, item));
+                    } else {
+                        self.buffers[side].push_back(item);
+                    }
+                    self.wake(side);
+                }
+                Ok(Async::Ready(None)) => {
+                    self.done = true;
+                    self.wake(other);
+                }
+                Ok(Async::NotReady) => {
+                    self.tasks[me] = Some(task::current());
+                    return Ok(Async::NotReady)
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn is_full(&self, side: usize) -> bool {
+        match self.capacity {
+            Some(cap) => self.buffers[side].len() >= cap,
+            None => false,
+        }
+    }
+
+    fn wake(&mut self, side: usize) {
+        if let Some(task) = self.tasks[side].take() {
+            task.notify();
+        }
+    }
+}
+
+impl<S: Stream, F> Shared<S, F> {
+    fn close_side(&mut self, side: usize) {
+        // Nobody is left to take this side's items, so they're discarded from
+        // here on, which may unblock the other side.
+        self.alive[side] = false;
+        self.buffers[side].clear();
+        let blocked = match self.blocked {
+            Some((blocked, _)) => blocked == side,
+            None => false,
+        };
+        if blocked {
+            self.blocked = None;
+        }
+        if let Some(task) = self.tasks[1 - side].take() {
+            task.notify();
+        }
+    }
+}
+
+impl<S: Stream, F> fmt::Debug for PartitionTrue<S, F> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("PartitionTrue")
+            .finish()
+    }
+}
+
+impl<S: Stream, F> fmt::Debug for PartitionFalse<S, F> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("PartitionFalse")
+            .finish()
+    }
+}
@@ -39,6 +39,10 @@ impl<S> ::sink::Sink for Peekable<S>
     fn close(&mut self) -> Poll<(), S::SinkError> {
         self.stream.close()
     }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
 }
 
 impl<S: Stream> Stream for Peekable<S> {
@@ -59,6 +63,12 @@ impl<S: Stream> Peekable<S> {
     ///
     /// This method polls the underlying stream and return either a reference
     /// to the next item if the stream is ready or passes through any errors.
+    ///
+    /// Like `poll`, it schedules the current task for wakeup when the
+    /// underlying stream isn't ready. Once an item has been buffered it is
+    /// returned by every subsequent call here, and by the next `poll`, without
+    /// the underlying stream being polled again. At the end of the stream this
+    /// returns `Ready(None)`.
     pub fn peek(&mut self) -> Poll<Option<&S::Item>, S::Error> {
         if self.peeked.is_some() {
             return Ok(Async::Ready(self.peeked.as_ref()))
@@ -71,4 +81,20 @@ impl<S: Stream> Peekable<S> {
             }
         }
     }
+
+    /// Puts an item back at the front of this stream.
+    ///
+    /// The next call to `peek` or `poll` will return `item` without polling
+    /// the underlying stream. This is useful for un-reading an item after
+    /// inspecting it, for example one taken out with `Stream::into_future`.
+    ///
+    /// Only a single item can be buffered, so if an item has already been
+    /// peeked at or put back then `item` is returned in `Err`.
+    pub fn push_back(&mut self, item: S::Item) -> Result<(), S::Item> {
+        if self.peeked.is_some() {
+            return Err(item)
+        }
+        self.peeked = Some(item);
+        Ok(())
+    }
 }
@@ -1,6 +1,6 @@
 //! Definition of the `PollFn` combinator
 
-use {Stream, Poll};
+use {Async, Stream, Poll};
 
 /// A stream which adapts a function returning `Poll`.
 ///
@@ -47,3 +47,61 @@ where
         (self.inner)()
     }
 }
+
+/// A stream which adapts a function returning `Poll` and threads a piece of
+/// owned state through each call.
+///
+/// Created by the `poll_fn_stateful` function.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct PollFnStateful<S, F> {
+    state: Option<S>,
+    inner: F,
+}
+
+/// Creates a new stream wrapping around a function returning `Poll` which is
+/// given mutable access to `state` each time it's called.
+///
+/// This is useful for small generators over owned state which isn't `Copy`
+/// and would otherwise be awkward to capture in a `poll_fn` closure. The
+/// stream ends once the function returns `Ready(None)`, at which point the
+/// state is dropped and the function won't be called again.
+///
+/// # Examples
+///
+/// ```
+/// use futures::stream::poll_fn_stateful;
+/// use futures::{Async, Future, Poll, Stream};
+///
+/// let countdown = poll_fn_stateful(3u32, |n: &mut u32| -> Poll<Option<u32>, ()> {
+///     if *n == 0 { return Ok(Async::Ready(None)); }
+///     *n -= 1;
+///     Ok(Async::Ready(Some(*n + 1)))
+/// });
+/// assert_eq!(countdown.collect().wait(), Ok(vec![3, 2, 1]));
+/// ```
+pub fn poll_fn_stateful<S, T, E, F>(state: S, f: F) -> PollFnStateful<S, F>
+where
+    F: FnMut(&mut S) -> Poll<Option<T>, E>,
+{
+    PollFnStateful { state: Some(state), inner: f }
+}
+
+impl<S, T, E, F> Stream for PollFnStateful<S, F>
+where
+    F: FnMut(&mut S) -> Poll<Option<T>, E>,
+{
+    type Item = T;
+    type Error = E;
+
+    fn poll(&mut self) -> Poll<Option<T>, E> {
+        let res = match self.state {
+            Some(ref mut state) => (self.inner)(state),
+            None => return Ok(Async::Ready(None)),
+        };
+        if let Ok(Async::Ready(None)) = res {
+            self.state = None;
+        }
+        res
+    }
+}
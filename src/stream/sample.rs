@@ -0,0 +1,142 @@
+use std::fmt;
+use std::time::Duration;
+
+use {Async, Poll};
+use stream::{self, Stream, Fuse, Interval};
+use task;
+use timer::Timer;
+
+/// The most items taken off the sampled stream in one call to `poll`, so that
+/// a stream which is always ready can't keep `poll` from returning.
+const MAX_DRAIN: usize = 32;
+
+/// A stream combinator which downsamples a stream, yielding its most recent
+/// item at a fixed interval.
+///
+/// This stream is created by the `Stream::sample` method.
+#[must_use = "streams do nothing unless polled"]
+pub struct Sample<S: Stream, T: Timer> {
+    stream: Fuse<S>,
+    ticks: Interval<T>,
+    latest: Option<S::Item>,
+}
+
+impl<S, T> fmt::Debug for Sample<S, T>
+    where S: Stream + fmt::Debug,
+          S::Item: fmt::Debug,
+          T: Timer + fmt::Debug,
+          T::Delay: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("Sample")
+            .field("stream", &self.stream)
+            .field("ticks", &self.ticks)
+            .field("latest", &self.latest)
+            .finish()
+    }
+}
+
+pub fn new<S, T>(stream: S, interval: Duration, timer: T) -> Sample<S, T>
+    where S: Stream,
+          T: Timer,
+{
+    Sample {
+        stream: stream.fuse(),
+        ticks: stream::interval(interval, timer),
+        latest: None,
+    }
+}
+
+impl<S, T> Sample<S, T>
+    where S: Stream,
+          T: Timer,
+{
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &S {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut S {
+        self.stream.get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+}
+
+impl<S, T> Stream for Sample<S, T>
+    where S: Stream,
+          T: Timer,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<S::Item>, S::Error> {
+        // Drain what the stream has ready so it never waits on us, keeping
+        // only the most recent item.
+        let mut drained = 0;
+        while let Async::Ready(Some(item)) = self.stream.poll()? {
+            self.latest = Some(item);
+            drained += 1;
+            if drained == MAX_DRAIN {
+                // The stream may have more items ready, come back for them
+                // rather than leaving them until the next tick.
+                task::current().notify();
+                break
+            }
+        }
+
+        loop {
+            if self.stream.is_done() && self.latest.is_none() {
+                return Ok(Async::Ready(None))
+            }
+
+            // Ticks which arrive before a new item are simply skipped over.
+            match self.ticks.poll() {
+                Ok(Async::Ready(Some(()))) => {
+                    if let Some(item) = self.latest.take() {
+                        return Ok(Async::Ready(Some(item)))
+                    }
+                }
+                Ok(Async::NotReady) => return Ok(Async::NotReady),
+                Ok(Async::Ready(None)) | Err(()) => return Ok(Async::Ready(None)),
+            }
+        }
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, T> ::sink::Sink for Sample<S, T>
+    where S: ::sink::Sink + Stream,
+          T: Timer,
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem) -> ::StartSend<S::SinkItem, S::SinkError> {
+        self.stream.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
+}
@@ -63,6 +63,10 @@ impl<S> ::sink::Sink for Skip<S>
     fn close(&mut self) -> Poll<(), S::SinkError> {
         self.stream.close()
     }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
 }
 
 impl<S> Stream for Skip<S>
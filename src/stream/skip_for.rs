@@ -0,0 +1,105 @@
+use std::time::Duration;
+
+use {Async, Future, Poll};
+use stream::Stream;
+use timer::Timer;
+
+/// A stream combinator which drops the items of a stream for a while, and
+/// passes the rest through.
+///
+/// This structure is produced by the `Stream::skip_for` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct SkipFor<S, D> {
+    stream: S,
+    delay: Option<D>,
+}
+
+pub fn new<S, T>(s: S, duration: Duration, timer: &T) -> SkipFor<S, T::Delay>
+    where S: Stream,
+          T: Timer,
+{
+    SkipFor {
+        stream: s,
+        delay: Some(timer.delay(timer.now() + duration)),
+    }
+}
+
+impl<S, D> SkipFor<S, D> {
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &S {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, D> ::sink::Sink for SkipFor<S, D>
+    where S: ::sink::Sink
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem) -> ::StartSend<S::SinkItem, S::SinkError> {
+        self.stream.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
+}
+
+impl<S, D> Stream for SkipFor<S, D>
+    where S: Stream,
+          D: Future<Item = (), Error = ()>,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<S::Item>, S::Error> {
+        loop {
+            // A failed timer can't tell the time anymore, so it's taken to
+            // mean that the duration has elapsed.
+            let elapsed = match self.delay {
+                Some(ref mut delay) => match delay.poll() {
+                    Ok(Async::NotReady) => false,
+                    Ok(Async::Ready(())) | Err(()) => true,
+                },
+                None => true,
+            };
+            if elapsed {
+                self.delay = None;
+                return self.stream.poll()
+            }
+
+            if try_ready!(self.stream.poll()).is_none() {
+                return Ok(Async::Ready(None))
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use {StartSend, Sink, Stream, Poll};
+
+/// A `Stream` part of the pair created by `Stream::split_local`
+#[derive(Debug)]
+pub struct LocalSplitStream<S>(Rc<RefCell<S>>);
+
+impl<S: Stream> Stream for LocalSplitStream<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<S::Item>, S::Error> {
+        self.0.borrow_mut().poll()
+    }
+}
+
+/// A `Sink` part of the pair created by `Stream::split_local`
+#[derive(Debug)]
+pub struct LocalSplitSink<S>(Rc<RefCell<S>>);
+
+impl<S: Sink> Sink for LocalSplitSink<S> {
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem)
+        -> StartSend<S::SinkItem, S::SinkError>
+    {
+        self.0.borrow_mut().start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.0.borrow_mut().poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.0.borrow_mut().close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.0.borrow_mut().poll_ready()
+    }
+}
+
+pub fn split_local<S: Stream + Sink>(s: S) -> (LocalSplitSink<S>, LocalSplitStream<S>) {
+    // Both halves live on the same thread and a poll of one can't happen in
+    // the middle of a poll of the other, so the borrows never overlap.
+    let inner = Rc::new(RefCell::new(s));
+    let read = LocalSplitStream(inner.clone());
+    let write = LocalSplitSink(inner);
+    (write, read)
+}
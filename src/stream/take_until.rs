@@ -0,0 +1,116 @@
+use {Async, Poll, Future};
+use stream::Stream;
+
+/// A stream combinator which takes elements from a stream until a signal
+/// future resolves.
+///
+/// This structure is produced by the `Stream::take_until` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct TakeUntil<S, F> where F: Future {
+    stream: S,
+    signal: Option<F>,
+    result: Option<Result<F::Item, F::Error>>,
+}
+
+pub fn new<S, F>(s: S, f: F) -> TakeUntil<S, F>
+    where S: Stream,
+          F: Future,
+{
+    TakeUntil {
+        stream: s,
+        signal: Some(f),
+        result: None,
+    }
+}
+
+impl<S, F> TakeUntil<S, F> where F: Future {
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &S {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+
+    /// Returns whether the signal future has resolved, ending this stream.
+    pub fn is_stopped(&self) -> bool {
+        self.signal.is_none()
+    }
+
+    /// Takes the result the signal future resolved with, if it has resolved
+    /// and the result hasn't been taken yet.
+    ///
+    /// This can be used to find out why the stream was stopped, including
+    /// whether the signal future failed.
+    pub fn take_result(&mut self) -> Option<Result<F::Item, F::Error>> {
+        self.result.take()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, F> ::sink::Sink for TakeUntil<S, F>
+    where S: ::sink::Sink + Stream, F: Future
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem) -> ::StartSend<S::SinkItem, S::SinkError> {
+        self.stream.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
+}
+
+impl<S, F> Stream for TakeUntil<S, F>
+    where S: Stream,
+          F: Future,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<S::Item>, S::Error> {
+        // The signal is checked first so the stream stops as soon as possible,
+        // even if it has more items ready.
+        let result = match self.signal {
+            Some(ref mut signal) => match signal.poll() {
+                Ok(Async::NotReady) => None,
+                Ok(Async::Ready(item)) => Some(Ok(item)),
+                Err(e) => Some(Err(e)),
+            },
+            None => return Ok(Async::Ready(None)),
+        };
+        if let Some(result) = result {
+            self.signal = None;
+            self.result = Some(result);
+            return Ok(Async::Ready(None))
+        }
+
+        self.stream.poll()
+    }
+}
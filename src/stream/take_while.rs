@@ -70,6 +70,10 @@ impl<S, P, R> ::sink::Sink for TakeWhile<S, P, R>
     fn close(&mut self) -> Poll<(), S::SinkError> {
         self.stream.close()
     }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
 }
 
 impl<S, P, R> Stream for TakeWhile<S, P, R>
@@ -0,0 +1,123 @@
+use {Async, AsyncSink, Poll};
+use sink::Sink;
+use stream::{Stream, Fuse};
+
+/// A stream combinator which sends a clone of every item of a stream to a
+/// sink before yielding it.
+///
+/// This structure is produced by the `Stream::tee` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Tee<S: Stream, K> {
+    stream: Fuse<S>,
+    side: K,
+    buffered: Option<S::Item>,
+}
+
+pub fn new<S, K>(stream: S, side: K) -> Tee<S, K>
+    where S: Stream,
+          S::Item: Clone,
+          K: Sink<SinkItem = S::Item>,
+          S::Error: From<K::SinkError>,
+{
+    Tee {
+        stream: stream.fuse(),
+        side: side,
+        buffered: None,
+    }
+}
+
+impl<S: Stream, K> Tee<S, K> {
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &S {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut S {
+        self.stream.get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+}
+
+impl<S, K> Tee<S, K>
+    where S: Stream,
+          S::Item: Clone,
+          K: Sink<SinkItem = S::Item>,
+          S::Error: From<K::SinkError>,
+{
+    fn try_send_side(&mut self, item: S::Item) -> Poll<Option<S::Item>, S::Error> {
+        debug_assert!(self.buffered.is_none());
+        if let AsyncSink::NotReady(_) = self.side.start_send(item.clone())? {
+            self.buffered = Some(item);
+            return Ok(Async::NotReady)
+        }
+        Ok(Async::Ready(Some(item)))
+    }
+}
+
+impl<S, K> Stream for Tee<S, K>
+    where S: Stream,
+          S::Item: Clone,
+          K: Sink<SinkItem = S::Item>,
+          S::Error: From<K::SinkError>,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<S::Item>, S::Error> {
+        // An item the sink didn't have room for has to be handed to it before
+        // it can be yielded.
+        if let Some(item) = self.buffered.take() {
+            return self.try_send_side(item)
+        }
+
+        match self.stream.poll()? {
+            Async::Ready(Some(item)) => self.try_send_side(item),
+            Async::Ready(None) => {
+                try_ready!(self.side.close());
+                Ok(Async::Ready(None))
+            }
+            Async::NotReady => {
+                try_ready!(self.side.poll_complete());
+                Ok(Async::NotReady)
+            }
+        }
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, K> Sink for Tee<S, K>
+    where S: Sink + Stream,
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem) -> ::StartSend<S::SinkItem, S::SinkError> {
+        self.stream.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
+}
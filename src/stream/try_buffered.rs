@@ -0,0 +1,155 @@
+use std::fmt;
+use std::mem;
+
+use {Async, IntoFuture, Poll};
+use stream::{Stream, Fuse, FuturesOrdered};
+
+/// An adaptor for a stream of futures to execute the futures concurrently, if
+/// possible, which ends at the first error.
+///
+/// This is like `Buffered`, except that the first error from either the
+/// underlying stream or one of the futures drops all other pending futures and
+/// ends the stream after that error. This is created by the
+/// `Stream::try_buffered` method.
+#[must_use = "streams do nothing unless polled"]
+pub struct TryBuffered<S>
+    where S: Stream,
+          S::Item: IntoFuture,
+{
+    stream: Fuse<S>,
+    queue: FuturesOrdered<<S::Item as IntoFuture>::Future>,
+    max: usize,
+    errored: bool,
+}
+
+impl<S> fmt::Debug for TryBuffered<S>
+    where S: Stream + fmt::Debug,
+          S::Item: IntoFuture,
+          <<S as Stream>::Item as IntoFuture>::Future: fmt::Debug,
+          <<S as Stream>::Item as IntoFuture>::Item: fmt::Debug,
+          <<S as Stream>::Item as IntoFuture>::Error: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("TryBuffered")
+            .field("stream", &self.stream)
+            .field("queue", &self.queue)
+            .field("max", &self.max)
+            .field("errored", &self.errored)
+            .finish()
+    }
+}
+
+pub fn new<S>(s: S, amt: usize) -> TryBuffered<S>
+    where S: Stream,
+          S::Item: IntoFuture<Error=<S as Stream>::Error>,
+{
+    TryBuffered {
+        stream: super::fuse::new(s),
+        queue: FuturesOrdered::new(),
+        max: amt,
+        errored: false,
+    }
+}
+
+impl<S> TryBuffered<S>
+    where S: Stream,
+          S::Item: IntoFuture<Error=<S as Stream>::Error>,
+{
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &S {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut S {
+        self.stream.get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+
+    fn poll_inner(&mut self) -> Poll<Option<<S::Item as IntoFuture>::Item>, S::Error> {
+        // First up, try to spawn off as many futures as possible by filling up
+        // our slab of futures.
+        while self.queue.len() < self.max {
+            let future = match self.stream.poll()? {
+                Async::Ready(Some(s)) => s.into_future(),
+                Async::Ready(None) |
+                Async::NotReady => break,
+            };
+
+            self.queue.push(future);
+        }
+
+        // Try polling a new future
+        if let Some(val) = try_ready!(self.queue.poll()) {
+            return Ok(Async::Ready(Some(val)));
+        }
+
+        if self.stream.is_done() {
+            Ok(Async::Ready(None))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S> ::sink::Sink for TryBuffered<S>
+    where S: ::sink::Sink + Stream,
+          S::Item: IntoFuture,
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem) -> ::StartSend<S::SinkItem, S::SinkError> {
+        self.stream.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
+}
+
+impl<S> Stream for TryBuffered<S>
+    where S: Stream,
+          S::Item: IntoFuture<Error=<S as Stream>::Error>,
+{
+    type Item = <S::Item as IntoFuture>::Item;
+    type Error = <S as Stream>::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        if self.errored {
+            return Ok(Async::Ready(None))
+        }
+
+        match self.poll_inner() {
+            Err(e) => {
+                // Cancel everything still in flight, nothing more will be
+                // yielded after this error.
+                self.errored = true;
+                drop(mem::replace(&mut self.queue, FuturesOrdered::new()));
+                Err(e)
+            }
+            res => res,
+        }
+    }
+}
@@ -101,6 +101,95 @@ impl <T, F, Fut, It> Stream for Unfold<T, F, Fut>
     }
 }
 
+/// Creates a `Stream` from a seed and a closure returning a `Future` which
+/// decides whether the stream goes on.
+///
+/// This is like `unfold()`, except that the closure is always called with the
+/// current state and it's the returned `Future` which tells whether there's
+/// another item: if it completes with `Some((a, b))` then `a` is yielded and
+/// `b` is used as the next internal state, if it completes with `None` the
+/// stream ends. This makes it easy to write fallible generators which only
+/// know whether they're done after doing some asynchronous work.
+///
+/// In case of error generated by the returned `Future`, the error will be
+/// returned by the `Stream`. The `Stream` will then yield
+/// `Ok(Async::Ready(None))` in future calls to `poll()`.
+///
+/// This function is also available as `try_unfold`.
+///
+/// # Example
+///
+/// ```rust
+/// use futures::stream::{self, Stream};
+///
+/// // Fibonacci numbers, failing once the next one doesn't fit in a `u8`.
+/// let stream = stream::unfold_result((0u8, 1u8), |(a, b)| {
+///     match a.checked_add(b) {
+///         Some(c) => Ok(Some((a, (b, c)))),
+///         None => Err(a),
+///     }
+/// });
+///
+/// let results: Vec<_> = stream.wait().collect();
+/// assert_eq!(results.len(), 13);
+/// assert_eq!(results[11], Ok(89));
+/// assert_eq!(results[12], Err(144));
+/// ```
+pub fn unfold_result<T, F, Fut, It>(init: T, f: F) -> UnfoldResult<T, F, Fut>
+    where F: FnMut(T) -> Fut,
+          Fut: IntoFuture<Item = Option<(It, T)>>,
+{
+    UnfoldResult {
+        f: f,
+        state: State::Ready(init),
+    }
+}
+
+/// A stream which creates futures, polls them and returns their result until
+/// one of them completes with `None`
+///
+/// This stream is returned by the `futures::stream::unfold_result` method
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct UnfoldResult<T, F, Fut> where Fut: IntoFuture {
+    f: F,
+    state: State<T, Fut::Future>,
+}
+
+impl <T, F, Fut, It> Stream for UnfoldResult<T, F, Fut>
+    where F: FnMut(T) -> Fut,
+          Fut: IntoFuture<Item = Option<(It, T)>>,
+{
+    type Item = It;
+    type Error = Fut::Error;
+
+    fn poll(&mut self) -> Poll<Option<It>, Fut::Error> {
+        loop {
+            match mem::replace(&mut self.state, State::Empty) {
+                // State::Empty may happen if the stream ended or the future
+                // returned an error
+                State::Empty => { return Ok(Async::Ready(None)); }
+                State::Ready(state) => {
+                    self.state = State::Processing((self.f)(state).into_future());
+                }
+                State::Processing(mut fut) => {
+                    match fut.poll()? {
+                        Async::Ready(Some((item, next_state))) => {
+                            self.state = State::Ready(next_state);
+                            return Ok(Async::Ready(Some(item)));
+                        }
+                        Async::Ready(None) => { return Ok(Async::Ready(None)); }
+                        Async::NotReady => {
+                            self.state = State::Processing(fut);
+                            return Ok(Async::NotReady);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 enum State<T, F> where F: Future {
     /// Placeholder state when doing work, or when the returned Future generated an error
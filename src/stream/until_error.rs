@@ -0,0 +1,109 @@
+use {Async, Poll};
+use stream::Stream;
+
+/// A stream combinator which ends at the first error of the underlying
+/// stream, keeping that error aside.
+///
+/// This structure is produced by the `Stream::until_error` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct UntilError<S> where S: Stream {
+    stream: S,
+    error: Option<S::Error>,
+    done: bool,
+}
+
+pub fn new<S>(s: S) -> UntilError<S>
+    where S: Stream,
+{
+    UntilError {
+        stream: s,
+        error: None,
+        done: false,
+    }
+}
+
+impl<S: Stream> UntilError<S> {
+    /// Returns a reference to the error which ended this stream, if any.
+    pub fn error(&self) -> Option<&S::Error> {
+        self.error.as_ref()
+    }
+
+    /// Consumes this combinator, returning the error which ended this stream,
+    /// if any.
+    pub fn into_error(self) -> Option<S::Error> {
+        self.error
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &S {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S> ::sink::Sink for UntilError<S>
+    where S: ::sink::Sink + Stream
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem) -> ::StartSend<S::SinkItem, S::SinkError> {
+        self.stream.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
+}
+
+impl<S> Stream for UntilError<S>
+    where S: Stream,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<S::Item>, S::Error> {
+        if self.done {
+            return Ok(Async::Ready(None))
+        }
+        match self.stream.poll() {
+            Ok(Async::Ready(None)) => {
+                self.done = true;
+                Ok(Async::Ready(None))
+            }
+            Err(e) => {
+                self.done = true;
+                self.error = Some(e);
+                Ok(Async::Ready(None))
+            }
+            other => other,
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::VecDeque;
+use std::prelude::v1::*;
+
+use {Async, Poll};
+use stream::{Stream, Fuse};
+
+/// An adaptor that yields overlapping windows of the most recent items of a
+/// stream.
+///
+/// This is created by the `Stream::windows` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Windows<S>
+    where S: Stream
+{
+    window: VecDeque<S::Item>,
+    size: usize,
+    stream: Fuse<S>,
+}
+
+pub fn new<S>(s: S, size: usize) -> Windows<S>
+    where S: Stream,
+          S::Item: Clone,
+{
+    assert!(size > 0);
+
+    Windows {
+        window: VecDeque::with_capacity(size),
+        size: size,
+        stream: super::fuse::new(s),
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S> ::sink::Sink for Windows<S>
+    where S: ::sink::Sink + Stream
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: S::SinkItem) -> ::StartSend<S::SinkItem, S::SinkError> {
+        self.stream.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.close()
+    }
+
+    fn poll_ready(&mut self) -> Poll<(), S::SinkError> {
+        self.stream.poll_ready()
+    }
+}
+
+impl<S> Windows<S> where S: Stream {
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &S {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut S {
+        self.stream.get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+}
+
+impl<S> Stream for Windows<S>
+    where S: Stream,
+          S::Item: Clone,
+{
+    type Item = Vec<<S as Stream>::Item>;
+    type Error = <S as Stream>::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        loop {
+            match try_ready!(self.stream.poll()) {
+                Some(item) => {
+                    if self.window.len() == self.size {
+                        self.window.pop_front();
+                    }
+                    self.window.push_back(item);
+                    if self.window.len() == self.size {
+                        let window = self.window.iter().cloned().collect();
+                        return Ok(Async::Ready(Some(window)))
+                    }
+                }
+                None => return Ok(Async::Ready(None)),
+            }
+        }
+    }
+}
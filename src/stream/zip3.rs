@@ -0,0 +1,70 @@
+use {Async, Poll};
+use stream::{Stream, Fuse};
+
+/// An adapter for zipping three streams together.
+///
+/// This stream is created by the `Stream::zip3` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Zip3<S1: Stream, S2: Stream, S3: Stream> {
+    stream1: Fuse<S1>,
+    stream2: Fuse<S2>,
+    stream3: Fuse<S3>,
+    queued1: Option<S1::Item>,
+    queued2: Option<S2::Item>,
+    queued3: Option<S3::Item>,
+}
+
+pub fn new<S1, S2, S3>(stream1: S1, stream2: S2, stream3: S3) -> Zip3<S1, S2, S3>
+    where S1: Stream,
+          S2: Stream<Error = S1::Error>,
+          S3: Stream<Error = S1::Error>,
+{
+    Zip3 {
+        stream1: stream1.fuse(),
+        stream2: stream2.fuse(),
+        stream3: stream3.fuse(),
+        queued1: None,
+        queued2: None,
+        queued3: None,
+    }
+}
+
+fn fill<S: Stream>(stream: &mut Fuse<S>, queued: &mut Option<S::Item>)
+    -> Result<(), S::Error>
+{
+    if queued.is_none() {
+        if let Async::Ready(Some(item)) = stream.poll()? {
+            *queued = Some(item);
+        }
+    }
+    Ok(())
+}
+
+impl<S1, S2, S3> Stream for Zip3<S1, S2, S3>
+    where S1: Stream,
+          S2: Stream<Error = S1::Error>,
+          S3: Stream<Error = S1::Error>,
+{
+    type Item = (S1::Item, S2::Item, S3::Item);
+    type Error = S1::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        fill(&mut self.stream1, &mut self.queued1)?;
+        fill(&mut self.stream2, &mut self.queued2)?;
+        fill(&mut self.stream3, &mut self.queued3)?;
+
+        if self.queued1.is_some() && self.queued2.is_some() &&
+           self.queued3.is_some() {
+            let triple = (self.queued1.take().unwrap(),
+                          self.queued2.take().unwrap(),
+                          self.queued3.take().unwrap());
+            Ok(Async::Ready(Some(triple)))
+        } else if self.stream1.is_done() || self.stream2.is_done() ||
+                  self.stream3.is_done() {
+            Ok(Async::Ready(None))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
+}
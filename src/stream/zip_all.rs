@@ -0,0 +1,74 @@
+use std::prelude::v1::*;
+
+use std::fmt;
+
+use {Async, Poll};
+use stream::{Stream, Fuse};
+
+/// A stream which zips together any number of streams of the same type.
+///
+/// This stream is created by the `stream::zip_all` function.
+#[must_use = "streams do nothing unless polled"]
+pub struct ZipAll<S: Stream> {
+    streams: Vec<(Fuse<S>, Option<S::Item>)>,
+}
+
+/// Zips together a list of streams, yielding a vector with one item from each
+/// of them, in the same order as the streams.
+///
+/// This is the counterpart of `Stream::zip` for a number of streams only
+/// known at runtime. Each stream is advanced by one item for every vector
+/// yielded. Errors from any of the streams are passed through immediately,
+/// and the zipped stream ends as soon as one of the streams ends. If there
+/// are no streams at all, it ends right away.
+pub fn zip_all<I>(streams: I) -> ZipAll<I::Item>
+    where I: IntoIterator,
+          I::Item: Stream,
+{
+    ZipAll {
+        streams: streams.into_iter().map(|s| (s.fuse(), None)).collect(),
+    }
+}
+
+impl<S: Stream> Stream for ZipAll<S> {
+    type Item = Vec<S::Item>;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<Vec<S::Item>>, S::Error> {
+        let mut ready = true;
+        let mut done = self.streams.is_empty();
+        for entry in self.streams.iter_mut() {
+            if entry.1.is_none() {
+                if let Async::Ready(Some(item)) = entry.0.poll()? {
+                    entry.1 = Some(item);
+                }
+            }
+            if entry.1.is_none() {
+                ready = false;
+                done = done || entry.0.is_done();
+            }
+        }
+
+        if done {
+            Ok(Async::Ready(None))
+        } else if ready {
+            let items = self.streams.iter_mut()
+                .map(|entry| entry.1.take().unwrap())
+                .collect();
+            Ok(Async::Ready(Some(items)))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
+}
+
+impl<S> fmt::Debug for ZipAll<S>
+    where S: Stream + fmt::Debug,
+          S::Item: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("ZipAll")
+            .field("streams", &self.streams)
+            .finish()
+    }
+}
@@ -0,0 +1,115 @@
+use {Async, Poll};
+use stream::{Stream, Fuse};
+use task;
+
+/// An adapter for combining the most recent items of two streams.
+///
+/// This stream yields a pair of the latest items of both streams whenever
+/// either of them produces a new item, once both have produced at least one.
+///
+/// This stream is created by the `Stream::zip_latest` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct ZipLatest<S1: Stream, S2: Stream> {
+    stream1: Fuse<S1>,
+    stream2: Fuse<S2>,
+    latest1: Option<S1::Item>,
+    latest2: Option<S2::Item>,
+    flag: bool,
+}
+
+pub fn new<S1, S2>(stream1: S1, stream2: S2) -> ZipLatest<S1, S2>
+    where S1: Stream,
+          S2: Stream<Error = S1::Error>,
+          S1::Item: Clone,
+          S2::Item: Clone,
+{
+    ZipLatest {
+        stream1: stream1.fuse(),
+        stream2: stream2.fuse(),
+        latest1: None,
+        latest2: None,
+        flag: false,
+    }
+}
+
+impl<S1, S2> ZipLatest<S1, S2>
+    where S1: Stream,
+          S2: Stream<Error = S1::Error>,
+          S1::Item: Clone,
+          S2::Item: Clone,
+{
+    fn poll1(&mut self) -> Result<bool, S1::Error> {
+        match self.stream1.poll()? {
+            Async::Ready(Some(item)) => {
+                self.latest1 = Some(item);
+                Ok(true)
+            }
+            Async::Ready(None) | Async::NotReady => Ok(false),
+        }
+    }
+
+    fn poll2(&mut self) -> Result<bool, S1::Error> {
+        match self.stream2.poll()? {
+            Async::Ready(Some(item)) => {
+                self.latest2 = Some(item);
+                Ok(true)
+            }
+            Async::Ready(None) | Async::NotReady => Ok(false),
+        }
+    }
+
+    fn pair(&self) -> Option<(S1::Item, S2::Item)> {
+        match (self.latest1.as_ref(), self.latest2.as_ref()) {
+            (Some(a), Some(b)) => Some((a.clone(), b.clone())),
+            _ => None,
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        // Once a stream ends without ever producing an item no more pairs can
+        // be formed, so there's no need to wait on the other one.
+        (self.stream1.is_done() && self.stream2.is_done()) ||
+            (self.stream1.is_done() && self.latest1.is_none()) ||
+            (self.stream2.is_done() && self.latest2.is_none())
+    }
+}
+
+impl<S1, S2> Stream for ZipLatest<S1, S2>
+    where S1: Stream,
+          S2: Stream<Error = S1::Error>,
+          S1::Item: Clone,
+          S2::Item: Clone,
+{
+    type Item = (S1::Item, S2::Item);
+    type Error = S1::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        // Alternate which stream goes first so a busy stream can't starve the
+        // other one out.
+        self.flag = !self.flag;
+
+        let mut progress = false;
+        for &first in &[self.flag, !self.flag] {
+            let updated = if first { self.poll1()? } else { self.poll2()? };
+            if updated {
+                if let Some(pair) = self.pair() {
+                    return Ok(Async::Ready(Some(pair)))
+                }
+                progress = true;
+            }
+        }
+
+        if self.is_done() {
+            return Ok(Async::Ready(None))
+        }
+        if progress {
+            // One side has an item but the other has yet to produce one. The
+            // side which made progress may still be ready, yet it can't be
+            // polled again here as that would spin for as long as it keeps
+            // producing items, so yield and get polled again later instead.
+            task::current().notify();
+        }
+        Ok(Async::NotReady)
+    }
+}
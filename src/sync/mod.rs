@@ -12,6 +12,10 @@
 
 pub mod oneshot;
 pub mod mpsc;
+pub mod resettable_oneshot;
+pub mod watch;
 mod bilock;
+mod semaphore;
 
 pub use self::bilock::{BiLock, BiLockGuard, BiLockAcquire, BiLockAcquired};
+pub use self::semaphore::{Semaphore, SemaphoreAcquire, SemaphorePermit};
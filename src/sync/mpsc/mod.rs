@@ -70,9 +70,10 @@
 use std::fmt;
 use std::error::Error;
 use std::any::Any;
+use std::collections::VecDeque;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering::SeqCst;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, Weak};
 use std::thread;
 use std::usize;
 
@@ -89,9 +90,9 @@ mod queue;
 ///
 /// This is created by the `channel` method.
 #[derive(Debug)]
-pub struct Sender<T> {
+pub struct Sender<T, E = ()> {
     // Channel state shared between the sender and receiver.
-    inner: Arc<Inner<T>>,
+    inner: Arc<Inner<T, E>>,
 
     // Handle to the task that is blocked on this sender. This handle is sent
     // to the receiver half in order to be notified when the sender becomes
@@ -107,7 +108,20 @@ pub struct Sender<T> {
 ///
 /// This is created by the `unbounded` method.
 #[derive(Debug)]
-pub struct UnboundedSender<T>(Sender<T>);
+pub struct UnboundedSender<T, E = ()>(Sender<T, E>);
+
+/// A weak reference to the transmission end of a channel.
+///
+/// Unlike a `Sender`, a `WeakSender` doesn't keep the channel open: once all
+/// `Sender`s are dropped the receiver sees the end of the stream, even if
+/// `WeakSender`s are still around. It can be upgraded to a `Sender` as long
+/// as the channel is still open.
+///
+/// This is created by the `Sender::downgrade` method.
+#[derive(Debug)]
+pub struct WeakSender<T, E = ()> {
+    inner: Weak<Inner<T, E>>,
+}
 
 trait AssertKinds: Send + Sync + Clone {}
 impl AssertKinds for UnboundedSender<u32> {}
@@ -119,8 +133,8 @@ impl AssertKinds for UnboundedSender<u32> {}
 /// a stream of values being computed elsewhere. This is created by the
 /// `channel` method.
 #[derive(Debug)]
-pub struct Receiver<T> {
-    inner: Arc<Inner<T>>,
+pub struct Receiver<T, E = ()> {
+    inner: Arc<Inner<T, E>>,
 }
 
 /// The receiving end of a channel which implements the `Stream` trait.
@@ -129,7 +143,7 @@ pub struct Receiver<T> {
 /// a stream of values being computed elsewhere. This is created by the
 /// `unbounded` method.
 #[derive(Debug)]
-pub struct UnboundedReceiver<T>(Receiver<T>);
+pub struct UnboundedReceiver<T, E = ()>(Receiver<T, E>);
 
 /// Error type for sending, used when the receiving end of a channel is
 /// dropped
@@ -236,7 +250,7 @@ impl<T> TrySendError<T> {
 }
 
 #[derive(Debug)]
-struct Inner<T> {
+struct Inner<T, E> {
     // Max buffer size of the channel. If `None` then the channel is unbounded.
     buffer: Option<usize>,
 
@@ -253,6 +267,22 @@ struct Inner<T> {
     // Number of senders in existence
     num_senders: AtomicUsize,
 
+    // What to do with messages sent while the channel is full
+    overflow: OverflowPolicy,
+
+    // Messages sent but not yet received when `overflow` isn't `Block`. The
+    // lock-free `message_queue` can't give up its oldest message, so such
+    // channels keep their messages here and only push the close sentinel onto
+    // `message_queue`.
+    overflow_queue: Mutex<VecDeque<T>>,
+
+    // Number of messages discarded because of `overflow`
+    num_dropped: AtomicUsize,
+
+    // Error handed to the receiver once the channel has been drained, set by
+    // the first call to `Sender::close_with_error`.
+    error: Mutex<Option<E>>,
+
     // Handle to the receiver's task.
     recv_task: Mutex<ReceiverTask>,
 }
@@ -318,6 +348,23 @@ impl SenderTask {
     }
 }
 
+/// What a bounded channel does with a message sent while it's full.
+///
+/// This is passed to `channel_with_overflow`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// The sender waits for room in the channel, which is what channels
+    /// created with `channel` do.
+    Block,
+
+    /// The message being sent is discarded, and the send reports success.
+    DropNewest,
+
+    /// The oldest message in the channel which hasn't been received yet is
+    /// discarded to make room for the one being sent.
+    DropOldest,
+}
+
 /// Creates an in-memory channel implementation of the `Stream` trait with
 /// bounded capacity.
 ///
@@ -355,13 +402,67 @@ pub fn unbounded<T>() -> (UnboundedSender<T>, UnboundedReceiver<T>) {
     (UnboundedSender(tx), UnboundedReceiver(rx))
 }
 
-fn channel2<T>(buffer: Option<usize>) -> (Sender<T>, Receiver<T>) {
+/// Creates a bounded channel, like `channel`, whose senders may close it with
+/// an error of type `E`.
+///
+/// The error passed to `Sender::close_with_error` is yielded by the `Receiver`
+/// once all messages sent before it have been received.
+pub fn channel_with_error<T, E>(buffer: usize) -> (Sender<T, E>, Receiver<T, E>) {
+    assert!(buffer < MAX_BUFFER, "requested buffer size too large");
+    channel2(Some(buffer))
+}
+
+/// Creates an unbounded channel, like `unbounded`, whose senders may close it
+/// with an error of type `E`.
+///
+/// The error passed to `UnboundedSender::close_with_error` is yielded by the
+/// `UnboundedReceiver` once all messages sent before it have been received.
+pub fn unbounded_with_error<T, E>() -> (UnboundedSender<T, E>, UnboundedReceiver<T, E>) {
+    let (tx, rx) = channel2(None);
+    (UnboundedSender(tx), UnboundedReceiver(rx))
+}
+
+/// Creates a bounded channel, like `channel`, which handles messages sent
+/// while it's full according to `overflow`.
+///
+/// With `OverflowPolicy::Block` this is the same as `channel`. With the other
+/// policies senders never wait: the channel holds at most `buffer` messages,
+/// without the extra slot per sender that `channel` has, and any message
+/// beyond that is discarded as specified by `overflow`.
+///
+/// The number of discarded messages is available through
+/// `Receiver::num_dropped`.
+///
+/// # Panics
+///
+/// This function panics if `overflow` isn't `OverflowPolicy::Block` and
+/// `buffer` is zero.
+pub fn channel_with_overflow<T>(buffer: usize, overflow: OverflowPolicy)
+    -> (Sender<T>, Receiver<T>)
+{
+    assert!(buffer < MAX_BUFFER, "requested buffer size too large");
+    assert!(buffer > 0 || overflow == OverflowPolicy::Block,
+            "a channel which drops messages needs a buffer");
+    channel3(Some(buffer), overflow)
+}
+
+fn channel2<T, E>(buffer: Option<usize>) -> (Sender<T, E>, Receiver<T, E>) {
+    channel3(buffer, OverflowPolicy::Block)
+}
+
+fn channel3<T, E>(buffer: Option<usize>, overflow: OverflowPolicy)
+    -> (Sender<T, E>, Receiver<T, E>)
+{
     let inner = Arc::new(Inner {
         buffer: buffer,
         state: AtomicUsize::new(INIT_STATE),
         message_queue: Queue::new(),
         parked_queue: Queue::new(),
         num_senders: AtomicUsize::new(1),
+        overflow: overflow,
+        overflow_queue: Mutex::new(VecDeque::new()),
+        num_dropped: AtomicUsize::new(0),
+        error: Mutex::new(None),
         recv_task: Mutex::new(ReceiverTask {
             unparked: false,
             task: None,
@@ -387,7 +488,7 @@ fn channel2<T>(buffer: Option<usize>) -> (Sender<T>, Receiver<T>) {
  *
  */
 
-impl<T> Sender<T> {
+impl<T, E> Sender<T, E> {
     /// Attempts to send a message on this `Sender<T>` without blocking.
     ///
     /// This function, unlike `start_send`, is safe to call whether it's being
@@ -417,6 +518,12 @@ impl<T> Sender<T> {
     // Do the send without failing
     // None means close
     fn do_send(&mut self, msg: Option<T>, do_park: bool) -> Result<(), SendError<T>> {
+        if self.inner.overflow != OverflowPolicy::Block {
+            if let Some(msg) = msg {
+                return self.do_send_overflow(msg);
+            }
+        }
+
         // First, increment the number of messages contained by the channel.
         // This operation will also atomically determine if the sender task
         // should be parked.
@@ -473,6 +580,41 @@ impl<T> Sender<T> {
         Ok(())
     }
 
+    // Send a message on a channel which discards messages instead of making
+    // senders wait, making room for it in `overflow_queue` as specified by
+    // `overflow`.
+    fn do_send_overflow(&self, msg: T) -> Result<(), SendError<T>> {
+        let mut queue = self.inner.overflow_queue.lock().unwrap();
+
+        if queue.len() < self.inner.buffer.unwrap() {
+            // There's room for the message, so it's tracked in the state like
+            // any other. The queue stays locked until it has been pushed so
+            // that the receiver never sees a count without the message.
+            match self.inc_num_messages(false) {
+                Some(park_self) => assert!(!park_self),
+                None => return Err(SendError(msg)),
+            }
+            queue.push_back(msg);
+        } else {
+            if !decode_state(self.inner.state.load(SeqCst)).is_open {
+                return Err(SendError(msg));
+            }
+            self.inner.num_dropped.fetch_add(1, SeqCst);
+            if self.inner.overflow == OverflowPolicy::DropNewest {
+                return Ok(());
+            }
+
+            // The message replaces the oldest one, so the number of messages
+            // in the channel doesn't change.
+            queue.pop_front();
+            queue.push_back(msg);
+        }
+
+        drop(queue);
+        self.signal();
+        Ok(())
+    }
+
     // Push message to the queue and signal to the receiver
     fn queue_push_and_signal(&self, msg: Option<T>) {
         // Push the message onto the message queue
@@ -515,7 +657,10 @@ impl<T> Sender<T> {
                     // Block if the current number of pending messages has exceeded
                     // the configured buffer size
                     let park_self = match self.inner.buffer {
-                        Some(buffer) => state.num_messages > buffer,
+                        Some(buffer) => {
+                            self.inner.overflow == OverflowPolicy::Block &&
+                                state.num_messages > buffer
+                        }
                         None => false,
                     };
 
@@ -599,6 +744,35 @@ impl<T> Sender<T> {
         Ok(self.poll_unparked(true))
     }
 
+    /// Closes the channel on behalf of all senders, recording `err` as the
+    /// reason.
+    ///
+    /// Messages which were sent before this call are still delivered, after
+    /// which the receiver yields `err` as its error, and then terminates. Any
+    /// further sends from other senders of this channel will fail.
+    ///
+    /// If several senders close the channel with an error, the first one to do
+    /// so wins and the other errors are dropped.
+    pub fn close_with_error(mut self, err: E) {
+        {
+            let mut slot = self.inner.error.lock().unwrap();
+            if slot.is_none() {
+                *slot = Some(err);
+            }
+        }
+        let _ = self.do_send(None, false);
+    }
+
+    /// Creates a new `WeakSender` for this channel.
+    ///
+    /// The returned `WeakSender` doesn't count as a sender for the purpose of
+    /// keeping the channel open, see `WeakSender` for more details.
+    pub fn downgrade(&self) -> WeakSender<T, E> {
+        WeakSender {
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+
     fn poll_unparked(&mut self, do_park: bool) -> Async<()> {
         // First check the `maybe_parked` variable. This avoids acquiring the
         // lock in most cases
@@ -630,7 +804,7 @@ impl<T> Sender<T> {
     }
 }
 
-impl<T> Sink for Sender<T> {
+impl<T, E> Sink for Sender<T, E> {
     type SinkItem = T;
     type SinkError = SendError<T>;
 
@@ -654,9 +828,19 @@ impl<T> Sink for Sender<T> {
     fn close(&mut self) -> Poll<(), SendError<T>> {
         Ok(Async::Ready(()))
     }
+
+    fn poll_ready(&mut self) -> Poll<(), SendError<T>> {
+        // A `SendError<T>` has to hand back a message, and there's none here,
+        // so a closed channel is reported as ready and left for `start_send`
+        // to fail with the message it's given.
+        match Sender::poll_ready(self) {
+            Ok(ready) => Ok(ready),
+            Err(SendError(())) => Ok(Async::Ready(())),
+        }
+    }
 }
 
-impl<T> UnboundedSender<T> {
+impl<T, E> UnboundedSender<T, E> {
     /// Sends the provided message along this channel.
     ///
     /// This is an unbounded sender, so this function differs from `Sink::send`
@@ -676,9 +860,17 @@ impl<T> UnboundedSender<T> {
     pub fn unbounded_send(&self, msg: T) -> Result<(), SendError<T>> {
         self.0.do_send_nb(msg)
     }
+
+    /// Closes the channel on behalf of all senders, recording `err` as the
+    /// reason.
+    ///
+    /// See `Sender::close_with_error` for more details.
+    pub fn close_with_error(self, err: E) {
+        self.0.close_with_error(err)
+    }
 }
 
-impl<T> Sink for UnboundedSender<T> {
+impl<T, E> Sink for UnboundedSender<T, E> {
     type SinkItem = T;
     type SinkError = SendError<T>;
 
@@ -695,7 +887,7 @@ impl<T> Sink for UnboundedSender<T> {
     }
 }
 
-impl<'a, T> Sink for &'a UnboundedSender<T> {
+impl<'a, T, E> Sink for &'a UnboundedSender<T, E> {
     type SinkItem = T;
     type SinkError = SendError<T>;
 
@@ -713,15 +905,15 @@ impl<'a, T> Sink for &'a UnboundedSender<T> {
     }
 }
 
-impl<T> Clone for UnboundedSender<T> {
-    fn clone(&self) -> UnboundedSender<T> {
+impl<T, E> Clone for UnboundedSender<T, E> {
+    fn clone(&self) -> UnboundedSender<T, E> {
         UnboundedSender(self.0.clone())
     }
 }
 
 
-impl<T> Clone for Sender<T> {
-    fn clone(&self) -> Sender<T> {
+impl<T, E> Clone for Sender<T, E> {
+    fn clone(&self) -> Sender<T, E> {
         // Since this atomic op isn't actually guarding any memory and we don't
         // care about any orderings besides the ordering on the single atomic
         // variable, a relaxed ordering is acceptable.
@@ -753,7 +945,7 @@ impl<T> Clone for Sender<T> {
     }
 }
 
-impl<T> Drop for Sender<T> {
+impl<T, E> Drop for Sender<T, E> {
     fn drop(&mut self) {
         // Ordering between variables don't matter here
         let prev = self.inner.num_senders.fetch_sub(1, SeqCst);
@@ -764,13 +956,58 @@ impl<T> Drop for Sender<T> {
     }
 }
 
+impl<T, E> WeakSender<T, E> {
+    /// Attempts to upgrade this `WeakSender` to a `Sender`.
+    ///
+    /// Returns `None` if all `Sender`s of the channel have already been
+    /// dropped, in which case the channel is closed for good.
+    pub fn upgrade(&self) -> Option<Sender<T, E>> {
+        self.inner.upgrade().and_then(|inner| {
+            let mut curr = inner.num_senders.load(SeqCst);
+
+            loop {
+                // Once the count drops to zero the channel has been closed,
+                // and it can't be revived.
+                if curr == 0 {
+                    return None
+                }
+
+                if curr == inner.max_senders() {
+                    panic!("cannot upgrade `WeakSender` -- too many outstanding senders");
+                }
+
+                let next = curr + 1;
+                let actual = inner.num_senders.compare_and_swap(curr, next, SeqCst);
+
+                if actual == curr {
+                    return Some(Sender {
+                        inner: inner,
+                        sender_task: Arc::new(Mutex::new(SenderTask::new())),
+                        maybe_parked: false,
+                    })
+                }
+
+                curr = actual;
+            }
+        })
+    }
+}
+
+impl<T, E> Clone for WeakSender<T, E> {
+    fn clone(&self) -> WeakSender<T, E> {
+        WeakSender {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
 /*
  *
  * ===== impl Receiver =====
  *
  */
 
-impl<T> Receiver<T> {
+impl<T, E> Receiver<T, E> {
     /// Closes the receiving half
     ///
     /// This prevents any further messages from being sent on the channel while
@@ -807,7 +1044,24 @@ impl<T> Receiver<T> {
         }
     }
 
+    /// Returns the number of messages which have been discarded because the
+    /// channel was full.
+    ///
+    /// This is always zero unless the channel was created with
+    /// `channel_with_overflow` and a policy which drops messages.
+    pub fn num_dropped(&self) -> usize {
+        self.inner.num_dropped.load(SeqCst)
+    }
+
     fn next_message(&mut self) -> Async<Option<T>> {
+        // Messages sent on a channel which discards messages come first, the
+        // close sentinel is only ever pushed after all of them.
+        if self.inner.overflow != OverflowPolicy::Block {
+            if let Some(msg) = self.inner.overflow_queue.lock().unwrap().pop_front() {
+                return Async::Ready(Some(msg));
+            }
+        }
+
         // Pop off a message
         loop {
             match unsafe { self.inner.message_queue.pop() } {
@@ -879,6 +1133,15 @@ impl<T> Receiver<T> {
         TryPark::Parked
     }
 
+    // Hand out the error the channel was closed with, if any. This is only
+    // ever returned once, after which the stream is simply terminated.
+    fn take_error(&self) -> Result<(), E> {
+        match self.inner.error.lock().unwrap().take() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
     fn dec_num_messages(&self) {
         let mut curr = self.inner.state.load(SeqCst);
 
@@ -896,11 +1159,11 @@ impl<T> Receiver<T> {
     }
 }
 
-impl<T> Stream for Receiver<T> {
+impl<T, E> Stream for Receiver<T, E> {
     type Item = T;
-    type Error = ();
+    type Error = E;
 
-    fn poll(&mut self) -> Poll<Option<T>, ()> {
+    fn poll(&mut self) -> Poll<Option<T>, E> {
         loop {
             // Try to read a message off of the message queue.
             let msg = match self.next_message() {
@@ -918,6 +1181,7 @@ impl<T> Stream for Receiver<T> {
                         TryPark::Closed => {
                             // The channel is closed, there will be no further
                             // messages.
+                            self.take_error()?;
                             return Ok(Async::Ready(None));
                         }
                         TryPark::NotEmpty => {
@@ -937,13 +1201,19 @@ impl<T> Stream for Receiver<T> {
             // Decrement number of messages
             self.dec_num_messages();
 
+            // The stream termination is preceded by the error the channel was
+            // closed with, if any.
+            if msg.is_none() {
+                self.take_error()?;
+            }
+
             // Return the message
             return Ok(Async::Ready(msg));
         }
     }
 }
 
-impl<T> Drop for Receiver<T> {
+impl<T, E> Drop for Receiver<T, E> {
     fn drop(&mut self) {
         // Drain the channel of all pending messages
         self.close();
@@ -953,7 +1223,7 @@ impl<T> Drop for Receiver<T> {
     }
 }
 
-impl<T> UnboundedReceiver<T> {
+impl<T, E> UnboundedReceiver<T, E> {
     /// Closes the receiving half
     ///
     /// This prevents any further messages from being sent on the channel while
@@ -963,11 +1233,11 @@ impl<T> UnboundedReceiver<T> {
     }
 }
 
-impl<T> Stream for UnboundedReceiver<T> {
+impl<T, E> Stream for UnboundedReceiver<T, E> {
     type Item = T;
-    type Error = ();
+    type Error = E;
 
-    fn poll(&mut self) -> Poll<Option<T>, ()> {
+    fn poll(&mut self) -> Poll<Option<T>, E> {
         self.0.poll()
     }
 }
@@ -1098,7 +1368,7 @@ impl<S: Stream> fmt::Debug for Execute<S> {
  *
  */
 
-impl<T> Inner<T> {
+impl<T, E> Inner<T, E> {
     // The return value is such that the total number of messages that can be
     // enqueued into the channel will never exceed MAX_CAPACITY
     fn max_senders(&self) -> usize {
@@ -1109,8 +1379,8 @@ impl<T> Inner<T> {
     }
 }
 
-unsafe impl<T: Send> Send for Inner<T> {}
-unsafe impl<T: Send> Sync for Inner<T> {}
+unsafe impl<T: Send, E: Send> Send for Inner<T, E> {}
+unsafe impl<T: Send, E: Send> Sync for Inner<T, E> {}
 
 /*
  *
@@ -340,6 +340,10 @@ impl<T> Sender<T> {
     /// scheduled to receive a notification if the corresponding `Receiver` goes
     /// away.
     ///
+    /// This makes it possible to `select` an expensive computation against
+    /// the `Receiver` going away, to abort it early. A value passed to `send`
+    /// once this has returned `Ready` is still handed back through `Err`.
+    ///
     /// # Panics
     ///
     /// Like `Future::poll`, this function will panic if it's not called from
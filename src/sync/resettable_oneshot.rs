@@ -0,0 +1,178 @@
+//! A futures-aware channel for repeated single-shot signaling
+//!
+//! This channel behaves like a `oneshot` which can be re-armed: after the
+//! `Receiver` has received a value it can be `reset` to wait for the next one,
+//! reusing the same allocation. At most one value is in flight at a time, a
+//! `send` while the previous value hasn't been received yet fails rather than
+//! buffering or overwriting it.
+
+use std::sync::{Arc, Mutex};
+
+use {Future, Poll, Async};
+use task::{self, Task};
+
+pub use sync::oneshot::Canceled;
+
+/// A future resolving to the next value sent through a resettable oneshot.
+///
+/// Once this future has resolved, `reset` must be called before it's polled
+/// again to receive the following value.
+///
+/// This is created by the `resettable_oneshot::channel` function.
+#[must_use = "futures do nothing unless polled"]
+#[derive(Debug)]
+pub struct Receiver<T> {
+    inner: Arc<Mutex<Inner<T>>>,
+    done: bool,
+}
+
+/// The sending half of a resettable oneshot, through which any number of
+/// values can be sent, one at a time.
+///
+/// This is created by the `resettable_oneshot::channel` function.
+#[derive(Debug)]
+pub struct Sender<T> {
+    inner: Arc<Mutex<Inner<T>>>,
+}
+
+#[derive(Debug)]
+struct Inner<T> {
+    /// The value sent but not yet received, if any.
+    data: Option<T>,
+
+    /// The task blocked in `Receiver::poll`.
+    rx_task: Option<Task>,
+
+    tx_dropped: bool,
+    rx_dropped: bool,
+}
+
+/// Creates a new futures-aware, resettable one-shot channel.
+///
+/// Like `oneshot::channel` this returns a `Sender` used to send a value and a
+/// `Receiver` future which resolves to it. Unlike a `oneshot`, though, the
+/// `Sender` isn't consumed by sending, and the `Receiver` can be `reset` after
+/// it has resolved to receive the next value.
+///
+/// # Examples
+///
+/// ```
+/// use futures::prelude::*;
+/// use futures::sync::resettable_oneshot;
+///
+/// let (tx, mut rx) = resettable_oneshot::channel::<u32>();
+///
+/// for i in 0..3 {
+///     tx.send(i).unwrap();
+///     assert_eq!((&mut rx).wait(), Ok(i));
+///     rx.reset();
+/// }
+/// ```
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let inner = Arc::new(Mutex::new(Inner {
+        data: None,
+        rx_task: None,
+        tx_dropped: false,
+        rx_dropped: false,
+    }));
+    let receiver = Receiver {
+        inner: inner.clone(),
+        done: false,
+    };
+    let sender = Sender {
+        inner: inner,
+    };
+    (sender, receiver)
+}
+
+impl<T> Sender<T> {
+    /// Sends a value to the `Receiver`.
+    ///
+    /// If the value is successfully handed over then `Ok(())` is returned. If
+    /// the `Receiver` has gone away, or it hasn't received the previously sent
+    /// value yet, then `Err` is returned with the value provided.
+    pub fn send(&self, t: T) -> Result<(), T> {
+        let task = {
+            let mut inner = self.inner.lock().unwrap();
+            if inner.rx_dropped || inner.data.is_some() {
+                return Err(t)
+            }
+            inner.data = Some(t);
+            inner.rx_task.take()
+        };
+        if let Some(task) = task {
+            task.notify();
+        }
+        Ok(())
+    }
+
+    /// Returns whether the previously sent value is still waiting to be
+    /// received, in which case `send` would fail.
+    pub fn is_pending(&self) -> bool {
+        self.inner.lock().unwrap().data.is_some()
+    }
+
+    /// Tests to see whether this `Sender`'s corresponding `Receiver` has gone
+    /// away.
+    pub fn is_canceled(&self) -> bool {
+        self.inner.lock().unwrap().rx_dropped
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let task = {
+            let mut inner = match self.inner.lock() {
+                Ok(inner) => inner,
+                Err(_) => return,
+            };
+            inner.tx_dropped = true;
+            inner.rx_task.take()
+        };
+        if let Some(task) = task {
+            task.notify();
+        }
+    }
+}
+
+impl<T> Receiver<T> {
+    /// Re-arms this `Receiver` after it has resolved, so it can be polled to
+    /// receive the next value.
+    ///
+    /// Calling this before the `Receiver` has resolved has no effect.
+    pub fn reset(&mut self) {
+        self.done = false;
+    }
+}
+
+impl<T> Future for Receiver<T> {
+    type Item = T;
+    type Error = Canceled;
+
+    fn poll(&mut self) -> Poll<T, Canceled> {
+        if self.done {
+            panic!("cannot poll Receiver twice without calling reset");
+        }
+
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(t) = inner.data.take() {
+            self.done = true;
+            return Ok(Async::Ready(t))
+        }
+        if inner.tx_dropped {
+            self.done = true;
+            return Err(Canceled)
+        }
+        inner.rx_task = Some(task::current());
+        Ok(Async::NotReady)
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.rx_dropped = true;
+            inner.rx_task = None;
+        }
+    }
+}
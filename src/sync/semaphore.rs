@@ -0,0 +1,191 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use {Async, Future, Poll};
+use task::{self, Task};
+
+/// A futures-aware counting semaphore, limiting how many tasks can hold one
+/// of a fixed number of permits at a time.
+///
+/// Permits are acquired with `acquire`, which returns a future resolving to a
+/// `SemaphorePermit` once one is available, or with `try_acquire`. A permit
+/// is given back to the semaphore when its `SemaphorePermit` is dropped.
+///
+/// Waiters are served in the order in which they started waiting: a permit
+/// which is given back goes to the task which has been waiting the longest,
+/// and neither `acquire` nor `try_acquire` can take a permit ahead of tasks
+/// which are already waiting.
+///
+/// A `Semaphore` is a handle which can be cloned cheaply, all of its clones
+/// share the same permits.
+///
+/// # Examples
+///
+/// ```
+/// use futures::prelude::*;
+/// use futures::sync::Semaphore;
+///
+/// let semaphore = Semaphore::new(1);
+/// let permit = semaphore.acquire().wait().unwrap();
+/// assert!(semaphore.try_acquire().is_none());
+///
+/// drop(permit);
+/// assert!(semaphore.try_acquire().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Semaphore {
+    inner: Arc<Mutex<Inner>>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    /// The number of permits which aren't held by anyone.
+    permits: usize,
+
+    /// The tasks waiting for a permit, oldest first, along with the ids of
+    /// their `SemaphoreAcquire` futures.
+    waiters: VecDeque<(usize, Task)>,
+
+    /// The id given to the next `SemaphoreAcquire` which has to wait.
+    next_id: usize,
+}
+
+impl Inner {
+    /// Notifies the first waiter if there's a permit available for it.
+    fn notify_first(&self) {
+        if self.permits > 0 {
+            if let Some(waiter) = self.waiters.front() {
+                waiter.1.notify();
+            }
+        }
+    }
+}
+
+/// A permit acquired from a `Semaphore`, which is given back when dropped.
+///
+/// This is created by the `Semaphore::acquire` and `Semaphore::try_acquire`
+/// methods.
+#[derive(Debug)]
+pub struct SemaphorePermit {
+    inner: Arc<Mutex<Inner>>,
+}
+
+/// Future resolving to a `SemaphorePermit` once one is available.
+///
+/// Dropping this future before it has resolved gives up its place in the
+/// queue of waiters.
+///
+/// This is created by the `Semaphore::acquire` method.
+#[must_use = "futures do nothing unless polled"]
+#[derive(Debug)]
+pub struct SemaphoreAcquire {
+    inner: Arc<Mutex<Inner>>,
+    id: Option<usize>,
+}
+
+impl Semaphore {
+    /// Creates a new semaphore with `permits` permits available.
+    pub fn new(permits: usize) -> Semaphore {
+        Semaphore {
+            inner: Arc::new(Mutex::new(Inner {
+                permits: permits,
+                waiters: VecDeque::new(),
+                next_id: 0,
+            })),
+        }
+    }
+
+    /// Returns a future which resolves to a permit once one is available.
+    pub fn acquire(&self) -> SemaphoreAcquire {
+        SemaphoreAcquire {
+            inner: self.inner.clone(),
+            id: None,
+        }
+    }
+
+    /// Attempts to acquire a permit without waiting.
+    ///
+    /// Returns `None` if no permit is available, or if there are tasks
+    /// already waiting for one.
+    pub fn try_acquire(&self) -> Option<SemaphorePermit> {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.permits == 0 || !inner.waiters.is_empty() {
+            return None
+        }
+        inner.permits -= 1;
+        Some(SemaphorePermit {
+            inner: self.inner.clone(),
+        })
+    }
+
+    /// Returns the number of permits which aren't currently held.
+    pub fn available_permits(&self) -> usize {
+        self.inner.lock().unwrap().permits
+    }
+}
+
+impl Drop for SemaphorePermit {
+    fn drop(&mut self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.permits += 1;
+            inner.notify_first();
+        }
+    }
+}
+
+impl Future for SemaphoreAcquire {
+    type Item = SemaphorePermit;
+    type Error = ();
+
+    fn poll(&mut self) -> Poll<SemaphorePermit, ()> {
+        let mut inner = self.inner.lock().unwrap();
+        let first = match self.id {
+            Some(id) => inner.waiters.front().map(|w| w.0) == Some(id),
+            None => inner.waiters.is_empty(),
+        };
+
+        if first && inner.permits > 0 {
+            if self.id.take().is_some() {
+                inner.waiters.pop_front();
+            }
+            inner.permits -= 1;
+            // More than one permit may have been given back since the next
+            // waiter was last notified.
+            inner.notify_first();
+            return Ok(Async::Ready(SemaphorePermit {
+                inner: self.inner.clone(),
+            }))
+        }
+
+        match self.id {
+            Some(id) => {
+                let waiter = inner.waiters.iter_mut()
+                    .find(|w| w.0 == id)
+                    .expect("SemaphoreAcquire isn't waiting");
+                if !waiter.1.will_notify_current() {
+                    waiter.1 = task::current();
+                }
+            }
+            None => {
+                let id = inner.next_id;
+                inner.next_id = inner.next_id.wrapping_add(1);
+                inner.waiters.push_back((id, task::current()));
+                self.id = Some(id);
+            }
+        }
+        Ok(Async::NotReady)
+    }
+}
+
+impl Drop for SemaphoreAcquire {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            if let Ok(mut inner) = self.inner.lock() {
+                inner.waiters.retain(|w| w.0 != id);
+                // If this was the first waiter it may have been notified of
+                // a permit which it'll now never take.
+                inner.notify_first();
+            }
+        }
+    }
+}
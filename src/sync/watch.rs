@@ -0,0 +1,223 @@
+//! A futures-aware channel holding a single value which receivers watch for
+//! changes
+//!
+//! Unlike `mpsc`, a watch channel doesn't deliver every value sent through
+//! it. It only holds the latest value, and each `Receiver` is a stream which
+//! yields that value whenever it has changed since the `Receiver` last looked
+//! at it. Updates which happen while a receiver isn't polled are coalesced,
+//! the receiver only sees the newest value. This suits things like
+//! configuration which can be reloaded at runtime.
+
+use std::prelude::v1::*;
+
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+
+use {Async, Poll};
+use stream::Stream;
+use task::{self, Task};
+
+/// The sending half of a watch channel, used to update its value.
+///
+/// This is created by the `watch::channel` function.
+#[derive(Debug)]
+pub struct Sender<T> {
+    inner: Arc<Mutex<Inner<T>>>,
+}
+
+/// The receiving half of a watch channel.
+///
+/// This is a stream yielding the channel's value every time it has changed,
+/// which ends once the `Sender` has been dropped. A `Receiver` can be cloned,
+/// each clone watching the value on its own.
+///
+/// This is created by the `watch::channel` function.
+#[must_use = "streams do nothing unless polled"]
+#[derive(Debug)]
+pub struct Receiver<T> {
+    inner: Arc<Mutex<Inner<T>>>,
+    id: usize,
+
+    /// The version of the value which was last yielded, if any.
+    seen: Option<usize>,
+}
+
+#[derive(Debug)]
+struct Inner<T> {
+    /// Shared with the `Ref`s handed out by `Receiver::borrow`, so that they
+    /// don't keep the channel locked.
+    value: Arc<T>,
+
+    /// Incremented every time the value is set.
+    version: usize,
+
+    /// The tasks blocked in `Receiver::poll`, by receiver id.
+    rx_tasks: HashMap<usize, Task>,
+
+    /// The id of the next receiver to be created.
+    next_id: usize,
+
+    receivers: usize,
+    tx_dropped: bool,
+}
+
+/// A reference to the value of a watch channel at the time it was borrowed.
+///
+/// Holding this reference doesn't keep the channel from being updated, it
+/// keeps pointing to the same value when the channel is.
+///
+/// This is created by the `Receiver::borrow` method.
+#[derive(Debug)]
+pub struct Ref<T> {
+    value: Arc<T>,
+}
+
+/// Creates a new watch channel holding the value `initial`.
+///
+/// The returned `Receiver` yields `initial` as soon as it's polled, and then
+/// every new value passed to `Sender::set`.
+///
+/// # Examples
+///
+/// ```
+/// use futures::prelude::*;
+/// use futures::sync::watch;
+///
+/// let (tx, rx) = watch::channel("initial");
+/// tx.set("first").unwrap();
+/// tx.set("second").unwrap();
+/// drop(tx);
+///
+/// // The receiver missed "first", only the latest value is left
+/// assert_eq!(rx.collect().wait(), Ok(vec!["second"]));
+/// ```
+pub fn channel<T>(initial: T) -> (Sender<T>, Receiver<T>) {
+    let inner = Arc::new(Mutex::new(Inner {
+        value: Arc::new(initial),
+        version: 0,
+        rx_tasks: HashMap::new(),
+        next_id: 1,
+        receivers: 1,
+        tx_dropped: false,
+    }));
+    let receiver = Receiver {
+        inner: inner.clone(),
+        id: 0,
+        seen: None,
+    };
+    let sender = Sender {
+        inner: inner,
+    };
+    (sender, receiver)
+}
+
+impl<T> Sender<T> {
+    /// Replaces the value of the channel, notifying all receivers.
+    ///
+    /// If all receivers have gone away then `Err` is returned with the value
+    /// provided, and the value of the channel is left as it was.
+    pub fn set(&self, value: T) -> Result<(), T> {
+        let tasks = {
+            let mut inner = self.inner.lock().unwrap();
+            if inner.receivers == 0 {
+                return Err(value)
+            }
+            inner.value = Arc::new(value);
+            inner.version = inner.version.wrapping_add(1);
+            inner.rx_tasks.drain().map(|(_, task)| task).collect::<Vec<_>>()
+        };
+        for task in tasks {
+            task.notify();
+        }
+        Ok(())
+    }
+
+    /// Tests to see whether all of the receivers of this channel have gone
+    /// away.
+    pub fn is_canceled(&self) -> bool {
+        self.inner.lock().unwrap().receivers == 0
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let tasks = {
+            let mut inner = match self.inner.lock() {
+                Ok(inner) => inner,
+                Err(_) => return,
+            };
+            inner.tx_dropped = true;
+            inner.rx_tasks.drain().map(|(_, task)| task).collect::<Vec<_>>()
+        };
+        for task in tasks {
+            task.notify();
+        }
+    }
+}
+
+impl<T> Receiver<T> {
+    /// Returns a reference to the current value of the channel, without
+    /// waiting for it to change.
+    ///
+    /// This doesn't count as having seen the value, so polling this receiver
+    /// may still yield it.
+    pub fn borrow(&self) -> Ref<T> {
+        Ref {
+            value: self.inner.lock().unwrap().value.clone(),
+        }
+    }
+}
+
+impl<T: Clone> Stream for Receiver<T> {
+    type Item = T;
+    type Error = ();
+
+    fn poll(&mut self) -> Poll<Option<T>, ()> {
+        let mut inner = self.inner.lock().unwrap();
+        if self.seen != Some(inner.version) {
+            self.seen = Some(inner.version);
+            return Ok(Async::Ready(Some((*inner.value).clone())))
+        }
+        if inner.tx_dropped {
+            return Ok(Async::Ready(None))
+        }
+        inner.rx_tasks.insert(self.id, task::current());
+        Ok(Async::NotReady)
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    /// Creates a new receiver watching the same channel.
+    ///
+    /// The new receiver yields the current value as soon as it's polled, even
+    /// if this one has already seen it.
+    fn clone(&self) -> Receiver<T> {
+        let mut inner = self.inner.lock().unwrap();
+        let id = inner.next_id;
+        inner.next_id = inner.next_id.wrapping_add(1);
+        inner.receivers += 1;
+        Receiver {
+            inner: self.inner.clone(),
+            id: id,
+            seen: None,
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.receivers -= 1;
+            inner.rx_tasks.remove(&self.id);
+        }
+    }
+}
+
+impl<T> Deref for Ref<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
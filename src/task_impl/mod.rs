@@ -300,6 +300,25 @@ impl<T: ?Sized> Spawn<T> {
         self.enter(BorrowedUnpark::new(&mk, id), |s| s.poll())
     }
 
+    /// Polls the internal future once, discarding any notifications.
+    ///
+    /// This is like `poll_future_notify` with a `Notify` which ignores all
+    /// notifications, so nothing will ever tell when it's worth polling again
+    /// after `NotReady` is returned. It's mostly useful for probing futures in
+    /// tests, where a future is polled once to check whether it's ready.
+    pub fn poll_future_inline(&mut self) -> Poll<T::Item, T::Error>
+        where T: Future,
+    {
+        self.poll_future_notify(&NotifyHandle::from(NOOP_NOTIFY), 0)
+    }
+
+    /// Like `poll_future_inline`, except polls the underlying stream.
+    pub fn poll_stream_inline(&mut self) -> Poll<Option<T::Item>, T::Error>
+        where T: Stream,
+    {
+        self.poll_stream_notify(&NotifyHandle::from(NOOP_NOTIFY), 0)
+    }
+
     /// Invokes the underlying `start_send` method with this task in place.
     ///
     /// If the underlying operation returns `NotReady` then the `notify` value
@@ -653,6 +672,16 @@ impl Drop for NotifyHandle {
     }
 }
 
+/// A `Notify` which ignores all notifications, used by the `*_inline`
+/// methods of `Spawn`.
+struct NoopNotify;
+
+impl Notify for NoopNotify {
+    fn notify(&self, _id: usize) {}
+}
+
+const NOOP_NOTIFY: &'static NoopNotify = &NoopNotify;
+
 /// Marker for a `T` that is behind &'static.
 struct StaticRef<T>(PhantomData<T>);
 
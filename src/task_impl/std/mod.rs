@@ -18,6 +18,9 @@ pub use self::unpark_mutex::UnparkMutex;
 mod data;
 pub use self::data::*;
 
+mod test_executor;
+pub use self::test_executor::TestExecutor;
+
 mod task_rc;
 #[allow(deprecated)]
 #[cfg(feature = "with-deprecated")]
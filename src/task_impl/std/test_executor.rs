@@ -0,0 +1,140 @@
+use std::prelude::v1::*;
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::mem;
+use std::sync::{Arc, Mutex};
+
+use {Future, Async};
+use super::super::{Spawn, spawn, Notify, NotifyHandle};
+
+/// A single-threaded executor for tests which only polls tasks when told to.
+///
+/// Tasks are never polled in the background: each call to `step` polls the
+/// task at the front of the queue of notified tasks exactly once, in the order
+/// in which they were notified. Every notification is also recorded so that
+/// tests can assert exactly which tasks were woken up and in which order,
+/// without depending on timing or on threads being parked.
+///
+/// Tasks are identified by the `usize` returned by `spawn`.
+pub struct TestExecutor {
+    tasks: Vec<Option<Task>>,
+    notify: Arc<Notifications>,
+}
+
+type Task = Spawn<Box<Future<Item = (), Error = ()>>>;
+
+struct Notifications {
+    inner: Mutex<NotificationsInner>,
+}
+
+struct NotificationsInner {
+    /// Tasks waiting to be polled, in the order they were notified.
+    queue: VecDeque<usize>,
+
+    /// Every notification since `TestExecutor::notifications` last took them.
+    log: Vec<usize>,
+}
+
+impl TestExecutor {
+    /// Creates a new executor with no tasks.
+    pub fn new() -> TestExecutor {
+        TestExecutor {
+            tasks: Vec::new(),
+            notify: Arc::new(Notifications {
+                inner: Mutex::new(NotificationsInner {
+                    queue: VecDeque::new(),
+                    log: Vec::new(),
+                }),
+            }),
+        }
+    }
+
+    /// Adds a task running `future` to this executor, returning its id.
+    ///
+    /// The new task is queued to be polled for the first time, but this isn't
+    /// recorded as a notification.
+    pub fn spawn<F>(&mut self, future: F) -> usize
+        where F: Future<Item = (), Error = ()> + 'static,
+    {
+        let id = self.tasks.len();
+        self.tasks.push(Some(spawn(Box::new(future))));
+        self.notify.inner.lock().unwrap().queue.push_back(id);
+        id
+    }
+
+    /// Polls the next queued task once.
+    ///
+    /// Returns the id of the task which was polled, or `None` if no task was
+    /// queued. Tasks which have already completed are skipped over.
+    pub fn step(&mut self) -> Option<usize> {
+        while let Some(id) = self.next_queued() {
+            let done = match self.tasks[id] {
+                Some(ref mut task) => {
+                    let handle = NotifyHandle::from(self.notify.clone());
+                    match task.poll_future_notify(&handle, id) {
+                        Ok(Async::NotReady) => false,
+                        Ok(Async::Ready(())) | Err(()) => true,
+                    }
+                }
+                None => continue,
+            };
+            if done {
+                self.tasks[id] = None;
+            }
+            return Some(id)
+        }
+        None
+    }
+
+    /// Calls `step` until no task is queued anymore, returning the number of
+    /// times a task was polled.
+    pub fn run_until_stalled(&mut self) -> usize {
+        let mut polls = 0;
+        while self.step().is_some() {
+            polls += 1;
+        }
+        polls
+    }
+
+    /// Returns whether the task with the given id has completed.
+    pub fn is_done(&self, id: usize) -> bool {
+        self.tasks[id].is_none()
+    }
+
+    /// Takes the ids of the tasks which have been notified since this was
+    /// last called, in the order in which they were notified.
+    pub fn notifications(&mut self) -> Vec<usize> {
+        mem::replace(&mut self.notify.inner.lock().unwrap().log, Vec::new())
+    }
+
+    // Takes the id of the next queued task, without holding on to the lock
+    // while that task is polled as it may notify itself.
+    fn next_queued(&self) -> Option<usize> {
+        self.notify.inner.lock().unwrap().queue.pop_front()
+    }
+}
+
+impl Default for TestExecutor {
+    fn default() -> TestExecutor {
+        TestExecutor::new()
+    }
+}
+
+impl fmt::Debug for TestExecutor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TestExecutor")
+            .field("tasks", &self.tasks.len())
+            .finish()
+    }
+}
+
+impl Notify for Notifications {
+    fn notify(&self, id: usize) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.log.push(id);
+        if !inner.queue.contains(&id) {
+            inner.queue.push_back(id);
+        }
+    }
+}
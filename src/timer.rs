@@ -0,0 +1,47 @@
+//! An abstraction over timers, for the time-based combinators of this crate.
+//!
+//! This crate doesn't come with a timer of its own, as timers are usually
+//! provided by an event loop. Combinators which need to wait for some time
+//! instead take any type implementing `Timer`, so they work with whichever
+//! timer is at hand, including a mock one in tests.
+//!
+//! This module is only available when the `use_std` feature of this library
+//! is activated, and it is activated by default.
+
+use std::time::Instant;
+
+use Future;
+
+/// A source of futures which resolve at a given point in time.
+pub trait Timer {
+    /// The future returned by `delay`.
+    type Delay: Future<Item = (), Error = ()>;
+
+    /// Returns a future which resolves once the deadline `at` has been
+    /// reached.
+    ///
+    /// The future resolves immediately if `at` has already passed. It should
+    /// fail if the timer is no longer able to track time, for example
+    /// because its event loop has shut down.
+    fn delay(&self, at: Instant) -> Self::Delay;
+
+    /// Returns the current time, as seen by this timer.
+    ///
+    /// This defaults to `Instant::now`, but can be overridden by timers which
+    /// keep time of their own, such as mock timers in tests.
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+impl<'a, T: ?Sized + Timer> Timer for &'a T {
+    type Delay = T::Delay;
+
+    fn delay(&self, at: Instant) -> T::Delay {
+        (**self).delay(at)
+    }
+
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+}
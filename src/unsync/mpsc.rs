@@ -109,6 +109,24 @@ impl<T> Sink for Sender<T> {
     fn close(&mut self) -> Poll<(), SendError<T>> {
         Ok(Async::Ready(()))
     }
+
+    fn poll_ready(&mut self) -> Poll<(), SendError<T>> {
+        // A closed channel is left for `start_send` to report, as there's no
+        // message to hand back in the error here.
+        let shared = match self.shared.upgrade() {
+            Some(shared) => shared,
+            None => return Ok(Async::Ready(())),
+        };
+        let mut shared = shared.borrow_mut();
+
+        match shared.capacity {
+            Some(capacity) if shared.buffer.len() == capacity => {
+                shared.blocked_senders.push_back(task::current());
+                Ok(Async::NotReady)
+            }
+            _ => Ok(Async::Ready(())),
+        }
+    }
 }
 
 impl<T> Drop for Sender<T> {
@@ -107,6 +107,10 @@ impl<T> Sender<T> {
     /// able to receive a message if sent. The current task, however, is
     /// scheduled to receive a notification if the corresponding `Receiver` goes
     /// away.
+    ///
+    /// This makes it possible to `select` an expensive computation against
+    /// the `Receiver` going away, to abort it early. A value passed to `send`
+    /// once this has returned `Ready` is still handed back through `Err`.
     pub fn poll_cancel(&mut self) -> Poll<(), ()> {
         match self.inner.upgrade() {
             Some(inner) => {
@@ -1,9 +1,12 @@
 extern crate futures;
 
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::mpsc::{channel, TryRecvError};
 
 use futures::future::*;
 use futures::future;
+use futures::Async;
 use futures::executor;
 use futures::sync::oneshot::{self, Canceled};
 
@@ -64,6 +67,15 @@ fn result_smoke() {
     assert_done(|| f_err(1).then(|_| Err(2)), r_err(2));
 }
 
+#[test]
+fn test_from_option() {
+    assert_done(|| from_option(Some(1), || 2), Ok::<i32, i32>(1));
+    assert_done(|| from_option(None, || 2), Err::<i32, i32>(2));
+
+    // The error is only built when it's needed
+    from_option::<_, i32, _>(Some(1), || panic!()).wait().unwrap();
+}
+
 #[test]
 fn test_empty() {
     fn empty() -> Empty<i32, u32> { future::empty() }
@@ -251,6 +263,90 @@ fn collect_collects() {
     // TODO: needs more tests
 }
 
+#[test]
+fn join_all_results_keeps_going() {
+    assert_done(|| join_all_results(vec![f_ok(1), f_err(2), f_ok(3)]),
+                Ok(vec![Ok(1), Err(2), Ok(3)]));
+    assert_done(|| join_all_results(Vec::<Result<i32, u32>>::new()), Ok(vec![]));
+
+    let (a, b) = oneshot::channel::<i32>();
+    let mut f = executor::spawn(join_all_results(vec![
+        Box::new(b.map_err(|_| 0u32)) as Box<Future<Item = i32, Error = u32>>,
+        Box::new(f_err(2)),
+    ]));
+    assert!(f.poll_future_notify(&notify_noop(), 0).unwrap().is_not_ready());
+    a.send(1).unwrap();
+    assert_eq!(f.poll_future_notify(&notify_noop(), 0),
+               Ok(Async::Ready(vec![Ok(1), Err(2)])));
+}
+
+#[test]
+fn execute_error_returns_future() {
+    struct ShutDown;
+
+    impl<F: Future<Item = (), Error = ()>> Executor<F> for ShutDown {
+        fn execute(&self, f: F) -> Result<(), ExecuteError<F>> {
+            Err(ExecuteError::new(ExecuteErrorKind::Shutdown, f))
+        }
+    }
+
+    let (tx, rx) = oneshot::channel::<i32>();
+    let err = ShutDown.execute(rx.to_spawnable()).unwrap_err();
+    assert_eq!(err.kind(), ExecuteErrorKind::Shutdown);
+    assert_eq!(err.to_string(), "executor has shut down");
+    assert_eq!(format!("{:?}", err), "\"executor has shut down\"");
+
+    // The rejected future is handed back and still works
+    let f = err.into_future();
+    tx.send(1).unwrap();
+    assert_eq!(f.wait(), Ok(()));
+
+    let err = ExecuteError::new(ExecuteErrorKind::NoCapacity, f_ok(1));
+    assert_eq!(err.to_string(), "executor has no more capacity");
+}
+
+#[test]
+fn boxed_local() {
+    use std::rc::Rc;
+
+    let shared = Rc::new(1);
+    let f: LocalBoxFuture<i32, u32> = f_ok(1).map(move |x| *shared + x).boxed_local();
+    assert_eq!(executor::spawn(f).wait_future(), Ok(2));
+}
+
+#[test]
+fn drop_output_and_to_spawnable() {
+    struct Inline(Arc<AtomicUsize>);
+
+    impl<F: Future<Item = (), Error = ()>> Executor<F> for Inline {
+        fn execute(&self, f: F) -> Result<(), ExecuteError<F>> {
+            let _ = f.wait();
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    let ran = Arc::new(AtomicUsize::new(0));
+    let exec = Inline(ran.clone());
+    exec.execute(f_ok(1).drop_output().map_err(|_| ())).unwrap();
+    exec.execute(f_ok(1).to_spawnable()).unwrap();
+    exec.execute(f_err(2).to_spawnable()).unwrap();
+    assert_eq!(ran.load(Ordering::SeqCst), 3);
+
+    assert_done(|| f_ok(1).drop_output(), Ok(()));
+    assert_done(|| f_err(2).drop_output(), Err(2));
+    assert_done(|| f_err(2).to_spawnable(), Err(()));
+}
+
+#[test]
+fn map_ok_or_else() {
+    let describe = |f: FutureResult<i32, u32>| {
+        f.map_ok_or_else(|x| format!("ok {}", x), |e| format!("err {}", e))
+    };
+    assert_done(|| describe(f_ok(1)), Ok::<_, ()>("ok 1".to_string()));
+    assert_done(|| describe(f_err(2)), Ok::<_, ()>("err 2".to_string()));
+}
+
 #[test]
 fn select2() {
     fn d<T, U, E>(r: Result<(T, U), (E, U)>) -> Result<T, E> {
@@ -354,6 +450,61 @@ fn select2() {
     }
 }
 
+#[test]
+fn left_right_future() {
+    for &n in &[0, 4] {
+        let f = match n {
+            0 => f_ok(1).left_future(),
+            n => f_ok(n).map(|x| x * 2).right_future(),
+        };
+        assert_done(|| f, Ok(if n == 0 { 1 } else { 8 }));
+    }
+
+    let (tx, rx) = oneshot::channel::<u32>();
+    let mut f = executor::spawn(match tx.is_canceled() {
+        true => empty().left_future(),
+        false => rx.right_future(),
+    });
+    assert!(f.poll_future_notify(&notify_noop(), 0).unwrap().is_not_ready());
+    tx.send(3).unwrap();
+    assert_eq!(f.poll_future_notify(&notify_noop(), 0), Ok(Async::Ready(3)));
+}
+
+#[test]
+fn select_either() {
+    fn a<T, U>(e: Either<T, U>) -> Option<T> {
+        match e {
+            Either::A(t) => Some(t),
+            Either::B(_) => None,
+        }
+    }
+    fn b<T, U>(e: Either<T, U>) -> Option<U> {
+        match e {
+            Either::A(_) => None,
+            Either::B(u) => Some(u),
+        }
+    }
+
+    let never = empty::<(), u32>();
+    assert_eq!(never.select_either(f_ok(2)).wait().map(b).map_err(b), Ok(Some(2)));
+    let never = empty::<(), u32>();
+    assert_eq!(f_ok(2).select_either(never).wait().map(a).map_err(a), Ok(Some(2)));
+    let never = empty::<(), u32>();
+    assert_eq!(never.select_either(f_err(2)).wait().map(b).map_err(b), Err(Some(2)));
+
+    // The loser is dropped as soon as the winner completes
+    let (tx, rx) = oneshot::channel::<u32>();
+    let (tx2, rx2) = oneshot::channel::<&str>();
+    let mut f = executor::spawn(rx.select_either(rx2));
+    assert!(f.poll_future_notify(&notify_noop(), 0).unwrap().is_not_ready());
+    tx2.send("b").unwrap();
+    match f.poll_future_notify(&notify_noop(), 0) {
+        Ok(Async::Ready(e)) => assert_eq!(b(e), Some("b")),
+        _ => panic!("expected the second future to win"),
+    }
+    assert!(tx.is_canceled());
+}
+
 #[test]
 fn option() {
     assert_eq!(Ok(Some(())), Some(ok::<(), ()>(())).wait());
@@ -373,3 +524,150 @@ fn spawn_does_unsize() {
     let mut spawn_box: Box<executor::Spawn<Future<Item = (), Error = ()>>> = Box::new(spawn);
     spawn_box.poll_future_notify(&EMPTY, 0).unwrap();
 }
+
+#[test]
+fn poll_fn_stateful_steps() {
+    use std::rc::Rc;
+
+    let dropped = Rc::new(());
+    let f = poll_fn_stateful((0, dropped.clone()), |&mut (ref mut step, _)| {
+        *step += 1;
+        if *step < 3 {
+            Ok(Async::NotReady)
+        } else {
+            Ok::<_, ()>(Async::Ready(*step))
+        }
+    });
+    let mut f = executor::spawn(f);
+    assert_eq!(f.poll_future_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    assert_eq!(f.poll_future_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    assert_eq!(Rc::strong_count(&dropped), 2);
+    assert_eq!(f.poll_future_notify(&notify_noop(), 0), Ok(Async::Ready(3)));
+    assert_eq!(Rc::strong_count(&dropped), 1);
+}
+
+#[test]
+fn poll_fn_self_notifies() {
+    use futures::{task, Poll};
+
+    let mut polls = 0;
+    let f = poll_fn(move || -> Poll<u32, ()> {
+        polls += 1;
+        if polls < 3 {
+            task::current().notify();
+            return Ok(Async::NotReady)
+        }
+        Ok(Async::Ready(polls))
+    });
+    assert_eq!(f.wait(), Ok(3));
+}
+
+#[test]
+fn loop_fn_acc_paginates() {
+    // A fake paginated API with three pages, each pointing at the next one.
+    fn fetch_page(page: usize) -> FutureResult<(Vec<u32>, Option<usize>), ()> {
+        match page {
+            0 => ok((vec![1, 2, 3], Some(1))),
+            1 => ok((vec![4, 5], Some(2))),
+            2 => ok((vec![6], None)),
+            _ => err(()),
+        }
+    }
+
+    let pages = loop_fn_acc(0, 0, |page, count| {
+        fetch_page(page).map(move |(items, next)| {
+            let count = count + items.len();
+            match next {
+                Some(next) => Loop::Continue((next, count)),
+                None => Loop::Break(((), count)),
+            }
+        })
+    });
+    assert_eq!(pages.wait(), Ok(6));
+}
+
+#[test]
+fn notify_from_another_thread() {
+    use std::sync::atomic::AtomicBool;
+    use std::thread;
+    use futures::{task, Poll};
+
+    // Like a completion callback of a foreign library, the spawned thread only
+    // gets the task handle and has to wake the task up through it.
+    let done = Arc::new(AtomicBool::new(false));
+    let mut started = false;
+    let f = poll_fn(move || -> Poll<(), ()> {
+        if done.load(Ordering::SeqCst) {
+            return Ok(Async::Ready(()))
+        }
+        if !started {
+            started = true;
+            let task = task::current();
+            let done = done.clone();
+            thread::spawn(move || {
+                done.store(true, Ordering::SeqCst);
+                task.notify();
+            });
+        }
+        Ok(Async::NotReady)
+    });
+    assert_eq!(f.wait(), Ok(()));
+}
+
+#[test]
+fn ready_fn_retries_with_loop_fn() {
+    use std::cell::Cell;
+
+    let calls = Cell::new(0);
+    let attempt = ready_fn(|| {
+        calls.set(calls.get() + 1);
+        if calls.get() < 3 { Err(calls.get()) } else { Ok(calls.get()) }
+    });
+
+    let retried = loop_fn(0, |failures| {
+        attempt.clone().then(move |res| match res {
+            Ok(n) => Ok(Loop::Break((n, failures))),
+            Err(_) if failures < 5 => Ok(Loop::Continue(failures + 1)),
+            Err(e) => Err(e),
+        })
+    });
+    assert_eq!(retried.wait(), Ok((3, 2)));
+    assert_eq!(calls.get(), 3);
+}
+
+#[test]
+fn timeout_at() {
+    use std::time::Duration;
+
+    let timer = MockTimer::new();
+    let start = timer.start();
+    let deadline = start + Duration::from_secs(10);
+
+    let mut f = executor::spawn(empty::<i32, u32>().timeout_at(&timer, deadline));
+    assert_eq!(f.poll_future_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    timer.set(Duration::from_secs(9));
+    assert_eq!(f.poll_future_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    timer.set(Duration::from_secs(10));
+    assert_eq!(f.poll_future_notify(&notify_noop(), 0), Err(TimeoutError::Elapsed));
+
+    // Completing after the deadline still counts, as long as it's first
+    assert_done(|| f_ok(1).timeout_at(&timer, start), Ok(1));
+    assert_done(|| f_err(2).timeout_at(&timer, deadline), Err(TimeoutError::Inner(2)));
+}
+
+#[test]
+fn poll_inline() {
+    let mut f = executor::spawn(empty::<i32, u32>());
+    assert_eq!(f.poll_future_inline(), Ok(Async::NotReady));
+    assert_eq!(f.poll_future_inline(), Ok(Async::NotReady));
+
+    let (tx, rx) = oneshot::channel::<i32>();
+    let mut f = executor::spawn(rx);
+    assert_eq!(f.poll_future_inline(), Ok(Async::NotReady));
+    tx.send(1).unwrap();
+    assert_eq!(f.poll_future_inline(), Ok(Async::Ready(1)));
+
+    let mut s = executor::spawn(futures::stream::iter_ok::<_, ()>(vec![1]));
+    assert_eq!(s.poll_stream_inline(), Ok(Async::Ready(Some(1))));
+    assert_eq!(s.poll_stream_inline(), Ok(Async::Ready(None)));
+}
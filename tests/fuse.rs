@@ -1,7 +1,8 @@
 extern crate futures;
 
 use futures::prelude::*;
-use futures::future::ok;
+use futures::future::{err, ok};
+use futures::stream;
 use futures::executor;
 
 mod support;
@@ -13,3 +14,19 @@ fn fuse() {
     assert!(future.poll_future_notify(&notify_panic(), 0).unwrap().is_ready());
     assert!(future.poll_future_notify(&notify_panic(), 0).unwrap().is_not_ready());
 }
+
+#[test]
+fn fuse_after_error() {
+    let mut future = executor::spawn(err::<i32, u32>(2).fuse());
+    assert_eq!(future.poll_future_notify(&notify_panic(), 0), Err(2));
+    assert!(future.poll_future_notify(&notify_panic(), 0).unwrap().is_not_ready());
+}
+
+#[test]
+fn stream_fuse() {
+    let mut stream = executor::spawn(stream::iter_ok::<_, u32>(vec![1]).fuse());
+    assert_eq!(stream.poll_stream_notify(&notify_panic(), 0), Ok(Async::Ready(Some(1))));
+    assert_eq!(stream.poll_stream_notify(&notify_panic(), 0), Ok(Async::Ready(None)));
+    assert_eq!(stream.poll_stream_notify(&notify_panic(), 0), Ok(Async::Ready(None)));
+    assert!(stream.get_ref().is_done());
+}
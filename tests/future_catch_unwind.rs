@@ -0,0 +1,32 @@
+extern crate futures;
+
+use std::panic::AssertUnwindSafe;
+
+use futures::prelude::*;
+use futures::future;
+
+#[test]
+fn panic_on_second_poll() {
+    let mut polls = 0;
+    let future = future::poll_fn(|| -> Poll<i32, u32> {
+        polls += 1;
+        if polls == 2 {
+            panic!("second poll");
+        }
+        futures::task::current().notify();
+        Ok(Async::NotReady)
+    });
+
+    let res = AssertUnwindSafe(future).catch_unwind().wait();
+    let panic = res.unwrap_err();
+    assert_eq!(*panic.downcast_ref::<&str>().unwrap(), "second poll");
+}
+
+#[test]
+fn no_panic() {
+    let future = future::ok::<i32, u32>(2).catch_unwind();
+    assert_eq!(future.wait().ok().unwrap(), Ok(2));
+
+    let future = future::err::<i32, u32>(3).catch_unwind();
+    assert_eq!(future.wait().ok().unwrap(), Err(3));
+}
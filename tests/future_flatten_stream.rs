@@ -41,3 +41,19 @@ fn failed_future() {
     assert_eq!(Err(10), iter.next().unwrap());
     assert_eq!(None, iter.next());
 }
+
+#[test]
+fn failed_future_with_mapped_error() {
+    let future_of_a_stream = err::<PanickingStream<bool, String>, _>(10);
+    let stream = future_of_a_stream.flatten_stream_err(|e: u32| e.to_string());
+    let mut iter = stream.wait();
+    assert_eq!(Err("10".to_string()), iter.next().unwrap());
+    assert_eq!(None, iter.next());
+}
+
+#[test]
+fn successful_future_with_mapped_error() {
+    let future_of_a_stream = ok::<_, u32>(stream::iter_ok::<_, String>(vec![17, 19]));
+    let stream = future_of_a_stream.flatten_stream_err(|e| e.to_string());
+    assert_eq!(stream.collect().wait(), Ok(vec![17, 19]));
+}
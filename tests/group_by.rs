@@ -0,0 +1,152 @@
+extern crate futures;
+
+use std::thread;
+
+use futures::prelude::*;
+use futures::executor;
+use futures::stream::{iter_ok, GroupBuffering};
+use futures::sync::mpsc;
+
+mod support;
+use support::*;
+
+#[test]
+fn groups_by_key() {
+    let groups = iter_ok::<_, ()>(vec![1, 2, 3, 4, 5, 6, 7])
+        .group_by(|x| x % 3)
+        .collect()
+        .wait()
+        .unwrap();
+    let groups = groups.into_iter()
+        .map(|(key, group)| (key, group.collect().wait().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(groups, vec![(1, vec![1, 4, 7]), (2, vec![2, 5]), (0, vec![3, 6])]);
+}
+
+#[test]
+fn groups_drive_the_stream() {
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let mut outer = executor::spawn(rx.group_by(|x| x % 2));
+
+    tx.unbounded_send(1).unwrap();
+    let mut odd = match outer.poll_stream_notify(&notify_noop(), 0) {
+        Ok(Async::Ready(Some((1, group)))) => executor::spawn(group),
+        _ => panic!(),
+    };
+    assert_eq!(odd.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(1))));
+
+    // The odd group pulls the even item through on its own, which is then
+    // waiting for the outer stream to hand out.
+    tx.unbounded_send(2).unwrap();
+    tx.unbounded_send(3).unwrap();
+    assert_eq!(odd.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(3))));
+    assert_eq!(odd.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+
+    let even = match outer.poll_stream_notify(&notify_noop(), 0) {
+        Ok(Async::Ready(Some((0, group)))) => group,
+        _ => panic!(),
+    };
+    drop(tx);
+    assert_eq!(even.collect().wait(), Ok(vec![2]));
+    assert_eq!(odd.wait_stream(), None);
+    assert!(outer.wait_stream().is_none());
+}
+
+#[test]
+fn groups_on_separate_threads() {
+    let (tx, rx) = mpsc::channel::<i32>(1);
+    thread::spawn(move || {
+        drop(tx.send_all(iter_ok((0..100).collect::<Vec<_>>())).wait().unwrap());
+    });
+
+    let handles = rx.group_by(|x| x % 4)
+        .take(4)
+        .collect()
+        .wait()
+        .unwrap()
+        .into_iter()
+        .map(|(key, group)| {
+            thread::spawn(move || {
+                let items = group.collect().wait().unwrap();
+                assert!(items.iter().all(|x| x % 4 == key));
+                items.len()
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), 25);
+    }
+}
+
+#[test]
+fn bounded_pauses_stream() {
+    let mut outer = executor::spawn(iter_ok::<_, ()>(vec![1, 3, 2])
+        .group_by(|x| x % 2)
+        .buffering(GroupBuffering::Bounded(1)));
+
+    let mut odd = match outer.poll_stream_notify(&notify_noop(), 0) {
+        Ok(Async::Ready(Some((1, group)))) => executor::spawn(group),
+        _ => panic!(),
+    };
+
+    // `3` can't be routed to the full odd group, so nothing can make progress
+    assert_eq!(outer.poll_stream_notify(&notify_noop(), 0).map(|a| a.is_ready()),
+               Ok(false));
+
+    assert_eq!(odd.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(1))));
+    match outer.poll_stream_notify(&notify_noop(), 0) {
+        Ok(Async::Ready(Some((0, _)))) => {}
+        _ => panic!(),
+    }
+    assert_eq!(odd.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(3))));
+    assert_eq!(odd.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(None)));
+}
+
+#[test]
+fn drop_oldest_discards() {
+    let groups = iter_ok::<_, ()>(vec![1, 3, 5, 2])
+        .group_by(|x| x % 2)
+        .buffering(GroupBuffering::DropOldest(2))
+        .collect()
+        .wait()
+        .unwrap();
+    let groups = groups.into_iter()
+        .map(|(key, group)| (key, group.collect().wait().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(groups, vec![(1, vec![3, 5]), (0, vec![2])]);
+}
+
+#[test]
+#[should_panic]
+fn bounded_capacity_zero_panics() {
+    drop(iter_ok::<_, ()>(Vec::<i32>::new()).group_by(|x| *x).buffering(GroupBuffering::Bounded(0)));
+}
+
+#[test]
+fn dropped_group_starts_over() {
+    let mut outer = iter_ok::<_, ()>(vec![1, 2, 1]).group_by(|x| *x).wait();
+    let (key, first) = outer.next().unwrap().unwrap();
+    assert_eq!(key, 1);
+    drop(first);
+
+    let keys = outer.map(|r| r.unwrap().0).collect::<Vec<_>>();
+    assert_eq!(keys, vec![2, 1]);
+}
+
+#[test]
+fn errors_go_to_the_poller() {
+    let (tx, rx) = mpsc::unbounded::<Result<i32, i32>>();
+    let rx = rx.then(|r| r.unwrap());
+    let mut outer = executor::spawn(rx.group_by(|x| *x));
+
+    tx.unbounded_send(Ok(1)).unwrap();
+    let mut group = match outer.poll_stream_notify(&notify_noop(), 0) {
+        Ok(Async::Ready(Some((1, group)))) => executor::spawn(group),
+        _ => panic!(),
+    };
+    tx.unbounded_send(Err(5)).unwrap();
+    tx.unbounded_send(Ok(1)).unwrap();
+    assert_eq!(group.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(1))));
+    assert_eq!(group.poll_stream_notify(&notify_noop(), 0), Err(5));
+    assert_eq!(group.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(1))));
+}
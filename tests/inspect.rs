@@ -2,6 +2,7 @@ extern crate futures;
 
 use futures::prelude::*;
 use futures::future::{ok, err};
+use futures::stream::iter_result;
 
 #[test]
 fn smoke() {
@@ -21,3 +22,24 @@ fn smoke() {
 
     assert_eq!(counter, 40);
 }
+
+#[test]
+fn inspect_err() {
+    let mut calls = 0;
+    assert_eq!(ok::<u32, u32>(1).inspect_err(|_| calls += 1).wait(), Ok(1));
+    assert_eq!(calls, 0);
+    assert_eq!(err::<u32, u32>(2).inspect_err(|_| calls += 1).wait(), Err(2));
+    assert_eq!(calls, 1);
+
+    let mut errors = Vec::new();
+    {
+        let items = vec![Ok(1), Err(2), Ok(3), Err(4)];
+        let mut s = iter_result(items).inspect_err(|e| errors.push(*e)).wait();
+        assert_eq!(s.next(), Some(Ok(1)));
+        assert_eq!(s.next(), Some(Err(2)));
+        assert_eq!(s.next(), Some(Ok(3)));
+        assert_eq!(s.next(), Some(Err(4)));
+        assert_eq!(s.next(), None);
+    }
+    assert_eq!(errors, vec![2, 4]);
+}
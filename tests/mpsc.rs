@@ -6,7 +6,7 @@ extern crate futures;
 use futures::prelude::*;
 use futures::future::{lazy, ok};
 use futures::stream::unfold;
-use futures::sync::mpsc;
+use futures::sync::mpsc::{self, OverflowPolicy};
 
 use std::time::Duration;
 use std::thread;
@@ -490,3 +490,135 @@ fn try_send_fail() {
     assert_eq!(rx.next(), Some(Ok("goodbye")));
     assert!(rx.next().is_none());
 }
+
+#[test]
+fn close_with_error() {
+    let (tx, rx) = mpsc::channel_with_error::<i32, &str>(2);
+    let tx2 = tx.clone();
+    let mut rx = rx.wait();
+
+    let tx = tx.send(1).wait().unwrap();
+    let tx = tx.send(2).wait().unwrap();
+    tx.close_with_error("boom");
+
+    assert_eq!(rx.next(), Some(Ok(1)));
+    assert_eq!(rx.next(), Some(Ok(2)));
+
+    // The channel is closed for the remaining sender as well
+    assert!(tx2.clone().try_send(3).is_err());
+    tx2.close_with_error("too late");
+
+    assert_eq!(rx.next(), Some(Err("boom")));
+    assert!(rx.next().is_none());
+}
+
+#[test]
+fn unbounded_close_with_error() {
+    let (tx, rx) = mpsc::unbounded_with_error::<i32, u32>();
+    let mut rx = rx.wait();
+
+    tx.unbounded_send(1).unwrap();
+    tx.close_with_error(5);
+
+    assert_eq!(rx.next(), Some(Ok(1)));
+    assert_eq!(rx.next(), Some(Err(5)));
+    assert!(rx.next().is_none());
+}
+
+#[test]
+fn weak_sender() {
+    let (tx, rx) = mpsc::channel::<i32>(2);
+    let weak = tx.downgrade();
+    let mut rx = rx.wait();
+
+    let tx2 = weak.upgrade().unwrap();
+    tx.send(1).wait().unwrap();
+    assert_eq!(rx.next(), Some(Ok(1)));
+    tx2.send(2).wait().unwrap();
+
+    // The weak sender alone doesn't keep the channel open
+    assert_eq!(rx.next(), Some(Ok(2)));
+    assert!(rx.next().is_none());
+    assert!(weak.upgrade().is_none());
+    assert!(weak.clone().upgrade().is_none());
+}
+
+#[test]
+fn overflow_drop_oldest() {
+    let (mut tx, rx) = mpsc::channel_with_overflow::<i32>(2, OverflowPolicy::DropOldest);
+    for i in 1..4 {
+        tx.try_send(i).unwrap();
+    }
+    drop(tx);
+
+    let mut rx = rx.wait();
+    assert_eq!(rx.next(), Some(Ok(2)));
+    assert_eq!(rx.next(), Some(Ok(3)));
+    assert_eq!(rx.next(), None);
+    assert_eq!(rx.into_inner().num_dropped(), 1);
+}
+
+#[test]
+fn overflow_drop_oldest_stays_bounded() {
+    let (mut tx, rx) = mpsc::channel_with_overflow(4, OverflowPolicy::DropOldest);
+    let msg = Arc::new(());
+    for _ in 0..1000 {
+        tx.try_send(msg.clone()).unwrap();
+        // The channel holds on to at most 4 messages
+        assert!(Arc::strong_count(&msg) <= 5);
+    }
+    assert_eq!(rx.num_dropped(), 996);
+    drop(tx);
+    assert_eq!(rx.wait().count(), 4);
+}
+
+#[test]
+fn overflow_drop_newest() {
+    let (tx, rx) = mpsc::channel_with_overflow::<i32>(2, OverflowPolicy::DropNewest);
+    let tx = tx.send(1).wait().unwrap();
+    let mut tx = tx.send(2).wait().unwrap();
+    tx.try_send(3).unwrap();
+
+    let mut rx = rx.wait();
+    assert_eq!(rx.next(), Some(Ok(1)));
+    // Receiving a message makes room for the next one
+    tx.try_send(4).unwrap();
+    drop(tx);
+    assert_eq!(rx.next(), Some(Ok(2)));
+    assert_eq!(rx.next(), Some(Ok(4)));
+    assert_eq!(rx.next(), None);
+    assert_eq!(rx.into_inner().num_dropped(), 1);
+}
+
+#[test]
+fn sink_poll_ready() {
+    // Going through an adapter calls the `Sink` method rather than the
+    // inherent one, which has to forward it.
+    let (tx, rx) = mpsc::channel::<i32>(0);
+    let mut tx = tx.sink_map_err(|_| ());
+    let mut rx = futures::executor::spawn(rx);
+
+    lazy(|| {
+        assert_eq!(tx.poll_ready(), Ok(Async::Ready(())));
+        assert!(tx.start_send(1).unwrap().is_ready());
+        assert_eq!(tx.poll_ready(), Ok(Async::NotReady));
+
+        assert_eq!(rx.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(1))));
+        assert_eq!(tx.poll_ready(), Ok(Async::Ready(())));
+        ok::<(), ()>(())
+    }).wait().unwrap();
+}
+
+#[test]
+fn sink_poll_ready_closed() {
+    let (mut tx, rx) = mpsc::channel::<i32>(0);
+    drop(rx);
+
+    lazy(|| {
+        // Only the inherent method can report the closed channel up front
+        assert!(tx.poll_ready().is_err());
+        assert_eq!(Sink::poll_ready(&mut tx), Ok(Async::Ready(())));
+        assert_eq!(tx.start_send(1).unwrap_err().into_inner(), 1);
+        ok::<(), ()>(())
+    }).wait().unwrap();
+}
@@ -24,6 +24,37 @@ fn smoke_poll() {
     assert!(task.poll_future_notify(&notify_noop(), 0).unwrap().is_ready());
 }
 
+#[test]
+fn send_after_poll_cancel() {
+    let (mut tx, rx) = channel::<u32>();
+    let mut task = futures::executor::spawn(lazy(|| {
+        assert!(tx.poll_cancel().unwrap().is_not_ready());
+        drop(rx);
+        assert!(tx.poll_cancel().unwrap().is_ready());
+        ok::<_, ()>(tx)
+    }));
+    let tx = match task.poll_future_notify(&notify_noop(), 0) {
+        Ok(Async::Ready(tx)) => tx,
+        _ => panic!(),
+    };
+    assert_eq!(tx.send(1), Err(1));
+}
+
+#[test]
+fn poll_cancel_selects_against_work() {
+    let (mut tx, rx) = channel::<u32>();
+    let (tx2, rx2) = mpsc::channel();
+    let t = thread::spawn(move || {
+        rx2.recv().unwrap();
+        drop(rx);
+    });
+    let work = futures::future::empty::<(), ()>();
+    let canceled = futures::future::poll_fn(|| tx.poll_cancel());
+    tx2.send(()).unwrap();
+    assert!(work.select(canceled).wait().is_ok());
+    t.join().unwrap();
+}
+
 #[test]
 fn cancel_notifies() {
     let (tx, rx) = channel::<u32>();
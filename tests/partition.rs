@@ -0,0 +1,71 @@
+extern crate futures;
+
+use futures::prelude::*;
+use futures::executor;
+use futures::stream::iter_ok;
+use futures::sync::mpsc;
+
+mod support;
+use support::*;
+
+#[test]
+fn evens_and_odds() {
+    let (evens, odds) = iter_ok::<_, ()>(1..10).partition(|x| x % 2 == 0);
+    assert_eq!(evens.collect().wait(), Ok(vec![2, 4, 6, 8]));
+    assert_eq!(odds.collect().wait(), Ok(vec![1, 3, 5, 7, 9]));
+}
+
+#[test]
+fn sides_drive_the_stream() {
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let (evens, odds) = rx.partition(|x| x % 2 == 0);
+    let mut evens = executor::spawn(evens);
+    let mut odds = executor::spawn(odds);
+
+    assert_eq!(evens.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    assert_eq!(odds.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+
+    // The odd side pulls the even items through on its own.
+    tx.unbounded_send(2).unwrap();
+    tx.unbounded_send(4).unwrap();
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(odds.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(1))));
+    assert_eq!(evens.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(2))));
+    assert_eq!(evens.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(4))));
+
+    drop(tx);
+    assert_eq!(evens.wait_stream(), None);
+    assert_eq!(odds.wait_stream(), None);
+}
+
+#[test]
+fn bounded_holds_back_the_stream() {
+    let (evens, odds) = iter_ok::<_, ()>(vec![2, 4, 6, 1, 3])
+        .partition_bounded(|x| x % 2 == 0, 1);
+    let mut evens = executor::spawn(evens);
+    let mut odds = executor::spawn(odds);
+
+    // 2 is buffered and 4 is held back, so 1 can't be reached yet.
+    assert_eq!(odds.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    assert_eq!(evens.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(2))));
+    assert_eq!(odds.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    assert_eq!(evens.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(4))));
+    assert_eq!(evens.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(6))));
+    assert_eq!(odds.wait_stream(), Some(Ok(1)));
+    assert_eq!(odds.wait_stream(), Some(Ok(3)));
+    assert_eq!(odds.wait_stream(), None);
+    assert_eq!(evens.wait_stream(), None);
+}
+
+#[test]
+fn dropped_side_is_discarded() {
+    let (evens, odds) = iter_ok::<_, ()>(1..10).partition_bounded(|x| x % 2 == 0, 1);
+    drop(evens);
+    assert_eq!(odds.collect().wait(), Ok(vec![1, 3, 5, 7, 9]));
+}
+
+#[test]
+#[should_panic]
+fn bounded_capacity_zero_panics() {
+    let _ = iter_ok::<_, ()>(Vec::<i32>::new()).partition_bounded(|x| x % 2 == 0, 0);
+}
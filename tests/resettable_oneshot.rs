@@ -0,0 +1,76 @@
+extern crate futures;
+
+use std::thread;
+
+use futures::prelude::*;
+use futures::executor;
+use futures::sync::resettable_oneshot::*;
+
+mod support;
+use support::*;
+
+#[test]
+fn send_recv_three_times() {
+    let (tx, rx) = channel::<u32>();
+    let (ack_tx, ack_rx) = channel::<()>();
+
+    let worker = thread::spawn(move || {
+        let mut rx = rx;
+        let mut received = Vec::new();
+        for _ in 0..3 {
+            received.push((&mut rx).wait().unwrap());
+            rx.reset();
+            ack_tx.send(()).unwrap();
+        }
+        received
+    });
+
+    let mut ack_rx = ack_rx;
+    for i in 0..3 {
+        tx.send(i).unwrap();
+        (&mut ack_rx).wait().unwrap();
+        ack_rx.reset();
+    }
+    assert_eq!(worker.join().unwrap(), vec![0, 1, 2]);
+}
+
+#[test]
+fn send_while_pending_fails() {
+    let (tx, rx) = channel::<u32>();
+    tx.send(1).unwrap();
+    assert!(tx.is_pending());
+    assert_eq!(tx.send(2), Err(2));
+    assert_eq!(rx.wait(), Ok(1));
+}
+
+#[test]
+fn poll_notifies() {
+    let (tx, rx) = channel::<u32>();
+    let mut rx = executor::spawn(rx);
+    assert_eq!(rx.poll_future_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    tx.send(1).unwrap();
+    assert_eq!(rx.poll_future_notify(&notify_noop(), 0), Ok(Async::Ready(1)));
+
+    rx.get_mut().reset();
+    assert_eq!(rx.poll_future_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    drop(tx);
+    assert_eq!(rx.poll_future_notify(&notify_noop(), 0), Err(Canceled));
+}
+
+#[test]
+fn receiver_dropped() {
+    let (tx, rx) = channel::<u32>();
+    assert!(!tx.is_canceled());
+    drop(rx);
+    assert!(tx.is_canceled());
+    assert_eq!(tx.send(1), Err(1));
+}
+
+#[test]
+#[should_panic]
+fn poll_without_reset_panics() {
+    let (tx, mut rx) = channel::<u32>();
+    tx.send(1).unwrap();
+    assert_eq!((&mut rx).wait(), Ok(1));
+    let _ = rx.poll();
+}
@@ -0,0 +1,83 @@
+extern crate futures;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use futures::prelude::*;
+use futures::executor::{self, TestExecutor};
+use futures::sync::Semaphore;
+
+#[test]
+fn one_permit_serializes_acquirers() {
+    let semaphore = Semaphore::new(1);
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let permits = Rc::new(RefCell::new(Vec::new()));
+
+    let mut exec = TestExecutor::new();
+    let mut ids = Vec::new();
+    for i in 0..2 {
+        let log = log.clone();
+        let permits = permits.clone();
+        ids.push(exec.spawn(semaphore.acquire().map(move |permit| {
+            log.borrow_mut().push(i);
+            permits.borrow_mut().push(permit);
+        })));
+    }
+
+    assert_eq!(exec.run_until_stalled(), 2);
+    assert_eq!(*log.borrow(), vec![0]);
+    assert!(exec.is_done(ids[0]));
+    assert!(!exec.is_done(ids[1]));
+    assert!(semaphore.try_acquire().is_none());
+
+    // Giving back the first permit wakes up the second acquirer
+    permits.borrow_mut().remove(0);
+    assert_eq!(exec.notifications(), vec![ids[1]]);
+    assert_eq!(exec.run_until_stalled(), 1);
+    assert_eq!(*log.borrow(), vec![0, 1]);
+    assert_eq!(semaphore.available_permits(), 0);
+
+    permits.borrow_mut().clear();
+    assert_eq!(semaphore.available_permits(), 1);
+}
+
+#[test]
+fn waiters_are_served_in_order() {
+    let semaphore = Semaphore::new(1);
+    let permit = semaphore.try_acquire().unwrap();
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let mut exec = TestExecutor::new();
+    for i in 0..3 {
+        let log = log.clone();
+        exec.spawn(semaphore.acquire().map(move |_permit| {
+            log.borrow_mut().push(i);
+        }));
+    }
+    assert_eq!(exec.run_until_stalled(), 3);
+
+    // A waiter is queued, so try_acquire can't jump ahead of it
+    drop(permit);
+    assert!(semaphore.try_acquire().is_none());
+    exec.run_until_stalled();
+    assert_eq!(*log.borrow(), vec![0, 1, 2]);
+    assert_eq!(semaphore.available_permits(), 1);
+}
+
+#[test]
+fn dropped_waiter_passes_on_its_turn() {
+    let semaphore = Semaphore::new(1);
+    let permit = semaphore.try_acquire().unwrap();
+
+    let mut exec = TestExecutor::new();
+    let mut first = executor::spawn(semaphore.acquire());
+    assert!(first.poll_future_inline().unwrap().is_not_ready());
+    let second = exec.spawn(semaphore.acquire().map(|_| ()));
+    exec.run_until_stalled();
+
+    drop(permit);
+    drop(first);
+    assert_eq!(exec.notifications(), vec![second]);
+    exec.run_until_stalled();
+    assert!(exec.is_done(second));
+}
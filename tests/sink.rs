@@ -352,6 +352,24 @@ fn buffer() {
     }
 }
 
+#[test]
+fn buffer_poll_ready() {
+    let (sink, allow) = manual_allow::<i32>();
+    let sink = sink.buffer(2);
+    let sink = StartSendFut::new(sink, 0).wait().unwrap();
+    let mut sink = StartSendFut::new(sink, 1).wait().unwrap();
+
+    {
+        let flag = Flag::new();
+        let mut task = executor::spawn(futures::future::poll_fn(|| sink.poll_ready()));
+        assert_eq!(task.poll_future_notify(&flag, 0), Ok(Async::NotReady));
+        allow.start();
+        assert!(flag.get());
+        assert_eq!(task.poll_future_notify(&flag, 0), Ok(Async::Ready(())));
+    }
+    assert_eq!(sink.get_ref().data, vec![0, 1]);
+}
+
 #[test]
 fn fanout_smoke() {
     let sink1 = Vec::new();
@@ -415,6 +433,36 @@ fn map_err() {
     assert_eq!(tx.sink_map_err(|_| ()).start_send(()), Err(()));
 }
 
+// A sink which accepts everything but fails to close.
+struct FailingClose;
+
+impl Sink for FailingClose {
+    type SinkItem = ();
+    type SinkError = u32;
+
+    fn start_send(&mut self, _: ()) -> StartSend<(), u32> {
+        Ok(AsyncSink::Ready)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), u32> {
+        Ok(Async::Ready(()))
+    }
+
+    fn close(&mut self) -> Poll<(), u32> {
+        Err(7)
+    }
+}
+
+#[test]
+fn map_err_close() {
+    let mut sink = FailingClose.sink_map_err(|e| format!("closing failed: {}", e));
+    assert_eq!(sink.start_send(()), Ok(AsyncSink::Ready));
+    assert_eq!(sink.close(), Err("closing failed: 7".to_string()));
+
+    let mut sink: SinkFromErr<FailingClose, u64> = FailingClose.sink_from_err();
+    assert_eq!(sink.close(), Err(7u64));
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 struct FromErrTest;
 
@@ -436,3 +484,107 @@ fn from_err() {
     let tx = mpsc::channel(0).0;
     assert_eq!(tx.sink_from_err().start_send(()), Err(FromErrTest));
 }
+
+#[test]
+fn drain() {
+    let mut drain = futures::sink::drain::<i32, ()>();
+    assert_eq!(drain.start_send(1), Ok(AsyncSink::Ready));
+    assert_eq!(drain.poll_complete(), Ok(Async::Ready(())));
+    assert_eq!(drain.items_sent(), 1);
+
+    let (_, mut drain) = stream::iter_ok::<_, ()>(0..10)
+        .forward(drain)
+        .wait()
+        .unwrap();
+    assert_eq!(drain.items_sent(), 11);
+    assert_eq!(drain.close(), Ok(Async::Ready(())));
+}
+
+// Accepts everything, counting how often it's flushed
+struct CountFlushes {
+    data: Vec<i32>,
+    flushes: Rc<Cell<usize>>,
+    closed: bool,
+}
+
+impl CountFlushes {
+    fn new() -> CountFlushes {
+        CountFlushes {
+            data: Vec::new(),
+            flushes: Rc::new(Cell::new(0)),
+            closed: false,
+        }
+    }
+}
+
+impl Sink for CountFlushes {
+    type SinkItem = i32;
+    type SinkError = ();
+
+    fn start_send(&mut self, item: i32) -> StartSend<i32, ()> {
+        self.data.push(item);
+        Ok(AsyncSink::Ready)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), ()> {
+        self.flushes.set(self.flushes.get() + 1);
+        Ok(Async::Ready(()))
+    }
+
+    fn close(&mut self) -> Poll<(), ()> {
+        self.closed = true;
+        Ok(Async::Ready(()))
+    }
+}
+
+#[test]
+fn send_all_batched() {
+    let (sink, _) = CountFlushes::new()
+        .send_all_batched(stream::iter_ok(0..10), 4)
+        .wait()
+        .unwrap();
+    assert_eq!(sink.data, (0..10).collect::<Vec<_>>());
+    assert_eq!(sink.flushes.get(), 2);
+    assert!(sink.closed);
+
+    // A pause in the stream flushes whatever has been sent so far
+    let (tx, rx) = mpsc::unbounded();
+    let sink = CountFlushes::new();
+    let flushes = sink.flushes.clone();
+    let mut task = executor::spawn(sink.send_all_batched(rx, 4));
+    tx.unbounded_send(1).unwrap();
+    tx.unbounded_send(2).unwrap();
+    assert!(task.poll_future_notify(&notify_noop(), 0).unwrap().is_not_ready());
+    assert_eq!(flushes.get(), 1);
+    assert!(task.poll_future_notify(&notify_noop(), 0).unwrap().is_not_ready());
+    assert_eq!(flushes.get(), 1);
+}
+
+#[test]
+fn forward_with_flush_policy() {
+    use futures::stream::FlushPolicy;
+
+    let (_, sink) = stream::iter_ok::<_, ()>(0..10)
+        .forward_with(CountFlushes::new(), FlushPolicy::EveryN(3))
+        .wait()
+        .unwrap();
+    assert_eq!(sink.data, (0..10).collect::<Vec<_>>());
+    assert_eq!(sink.flushes.get(), 3);
+    assert!(sink.closed);
+
+    let (_, sink) = stream::iter_ok::<_, ()>(0..10)
+        .forward_with(CountFlushes::new(), FlushPolicy::EveryItem)
+        .wait()
+        .unwrap();
+    assert_eq!(sink.flushes.get(), 10);
+
+    // Bursts are coalesced into a single flush when the stream pauses
+    let (tx, rx) = mpsc::unbounded();
+    let sink = CountFlushes::new();
+    let flushes = sink.flushes.clone();
+    let mut task = executor::spawn(rx.forward_with(sink, FlushPolicy::WhenSourcePending));
+    tx.unbounded_send(1).unwrap();
+    tx.unbounded_send(2).unwrap();
+    assert!(task.poll_future_notify(&notify_noop(), 0).unwrap().is_not_ready());
+    assert_eq!(flushes.get(), 1);
+}
@@ -3,6 +3,9 @@ extern crate futures;
 use futures::prelude::*;
 use futures::stream::iter_ok;
 
+mod support;
+use support::*;
+
 struct Join<T, U>(T, U);
 
 impl<T: Stream, U> Stream for Join<T, U> {
@@ -45,3 +48,36 @@ fn test_split() {
     }
     assert_eq!(dest, vec![10, 20, 30]);
 }
+
+#[test]
+fn test_split_local() {
+    use futures::executor;
+    use futures::unsync::mpsc::{self, SendError};
+
+    // The split object is one end of an in-memory duplex, and the other end
+    // echoes back everything it receives, multiplied by ten.
+    let (tx1, rx1) = mpsc::channel(1);
+    let (tx2, rx2) = mpsc::channel(1);
+    let (sink, stream) = Join(rx2, tx1).split_local();
+
+    let mut writer = executor::spawn(sink.send_all(iter_ok::<_, SendError<i32>>(vec![1, 2, 3])));
+    let mut echo = executor::spawn(tx2.send_all(rx1.map(|x| x * 10)
+        .map_err(|()| -> SendError<i32> { panic!() })));
+    let mut reader = executor::spawn(stream.take(3).collect());
+
+    // Drive the three tasks in turn until the reader has seen everything.
+    let (mut writer_done, mut echo_done) = (false, false);
+    for _ in 0..100 {
+        if !writer_done {
+            writer_done = writer.poll_future_notify(&notify_noop(), 0).unwrap().is_ready();
+        }
+        if !echo_done {
+            echo_done = echo.poll_future_notify(&notify_noop(), 0).unwrap().is_ready();
+        }
+        if let Async::Ready(items) = reader.poll_future_notify(&notify_noop(), 0).unwrap() {
+            assert_eq!(items, vec![10, 20, 30]);
+            return
+        }
+    }
+    panic!("the split halves made no progress");
+}
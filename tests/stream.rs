@@ -3,8 +3,9 @@ extern crate futures;
 
 use futures::prelude::*;
 use futures::executor;
+use futures::stream;
 use futures::future::{err, ok};
-use futures::stream::{empty, iter_ok, poll_fn, Peekable};
+use futures::stream::{empty, iter_ok, iter_result, poll_fn, poll_fn_stateful, Peekable};
 use futures::sync::oneshot;
 use futures::sync::mpsc;
 
@@ -102,6 +103,16 @@ fn filter_map() {
     }).collect(), Ok(vec![12]));
 }
 
+#[test]
+fn filter_map_async() {
+    assert_done(|| iter_ok::<_, u32>(1..7).filter_map_async(|x| {
+        delay_future(Ok(if x % 2 == 0 { Some(x * x) } else { None }))
+    }).collect(), Ok(vec![4, 16, 36]));
+    assert_done(|| list().filter_map_async(|x| {
+        if x == 2 { err(5) } else { ok(Some(x)) }
+    }).collect(), Err(5));
+}
+
 #[test]
 fn and_then() {
     assert_done(|| list().and_then(|a| Ok(a + 1)).collect(), Ok(vec![2, 3, 4]));
@@ -115,11 +126,42 @@ fn then() {
 
 }
 
+#[test]
+fn iter_ok_and_iter_result() {
+    let mut s = stream::iter_ok::<_, u32>(vec![1, 2, 3]);
+    sassert_next(&mut s, 1);
+    sassert_next(&mut s, 2);
+    sassert_next(&mut s, 3);
+    sassert_done(&mut s);
+
+    let mut s = stream::iter_result(vec![Ok(1), Err(2), Ok(3)]);
+    sassert_next(&mut s, 1);
+    sassert_err(&mut s, 2);
+    sassert_next(&mut s, 3);
+    sassert_done(&mut s);
+}
+
 #[test]
 fn or_else() {
     assert_done(|| err_list().or_else(|a| {
         ok::<i32, u32>(a as i32)
     }).collect(), Ok(vec![1, 2, 3]));
+
+    // Every error is recovered from, and the stream carries on afterwards
+    let items = vec![Err(1), Ok(2), Err(3), Ok(4)];
+    assert_done(|| stream::iter_result(items).or_else(|_| ok::<i32, u32>(0)).collect(),
+                Ok(vec![0, 2, 0, 4]));
+
+    // A failed recovery is yielded as an error without ending the stream
+    let items = vec![Err(1), Ok(2), Err(3), Ok(4)];
+    let mut s = stream::iter_result(items)
+        .or_else(|e| if e == 1 { ok(0) } else { err(e * 10) })
+        .wait();
+    assert_eq!(s.next(), Some(Ok(0)));
+    assert_eq!(s.next(), Some(Ok(2)));
+    assert_eq!(s.next(), Some(Err(30)));
+    assert_eq!(s.next(), Some(Ok(4)));
+    assert_eq!(s.next(), None);
 }
 
 #[test]
@@ -129,6 +171,35 @@ fn flatten() {
 
 }
 
+#[test]
+fn flatten_unordered() {
+    let inner = |v: Vec<i32>| iter_ok::<_, u32>(v).and_then(|x| delay_future(Ok(x)));
+    let outer = iter_ok::<_, u32>(vec![vec![1, 2, 3], vec![10, 20, 30]]).map(inner);
+    assert_done(|| outer.flatten_unordered(2).collect(),
+                Ok(vec![1, 10, 2, 20, 3, 30]));
+
+    let outer = iter_ok::<_, u32>(vec![vec![1, 2, 3], vec![10, 20, 30]]).map(inner);
+    assert_done(|| outer.flatten_unordered(1).collect(),
+                Ok(vec![1, 2, 3, 10, 20, 30]));
+}
+
+#[test]
+#[should_panic]
+fn flatten_unordered_panics_on_zero() {
+    let _ = list().map(|_| list()).flatten_unordered(0);
+}
+
+#[test]
+fn flatten_unordered_passes_errors_through() {
+    let mut s = iter_ok::<_, u32>(vec![iter(vec![Ok(1), Err(2u32), Ok(3)])])
+        .flatten_unordered(2)
+        .wait();
+    assert_eq!(s.next(), Some(Ok(1)));
+    assert_eq!(s.next(), Some(Err(2)));
+    assert_eq!(s.next(), Some(Ok(3)));
+    assert_eq!(s.next(), None);
+}
+
 #[test]
 fn skip() {
     assert_done(|| list().skip(2).collect(), Ok(vec![3]));
@@ -151,6 +222,96 @@ fn skip_while() {
     assert_done(|| list().skip_while(|e| Ok(*e % 2 == 1)).collect(),
                 Ok(vec![2, 3]));
 }
+
+#[test]
+fn skip_while_async_predicate() {
+    let mut calls = 0;
+    let items = iter_ok::<_, u32>(vec![1, 2, 3, 4, 5])
+        .skip_while(|_| {
+            calls += 1;
+            delay_future(ok::<_, u32>(calls < 3))
+        })
+        .collect()
+        .wait();
+    assert_eq!(items, Ok(vec![3, 4, 5]));
+    assert_eq!(calls, 3);
+
+    let items = iter_ok::<_, u32>(vec![1, 2])
+        .skip_while(|_| delay_future(err::<bool, u32>(7)))
+        .collect()
+        .wait();
+    assert_eq!(items, Err(7));
+}
+
+#[test]
+fn take_until() {
+    let (tx, rx) = oneshot::channel::<u32>();
+    let mut s = executor::spawn(stream::repeat::<_, u32>(1).take_until(rx));
+
+    for _ in 0..3 {
+        assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(1))));
+    }
+    tx.send(5).unwrap();
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(None)));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(None)));
+    assert!(s.get_ref().is_stopped());
+    assert_eq!(s.get_mut().take_result(), Some(Ok(5)));
+    assert_eq!(s.get_mut().take_result(), None);
+
+    // A failed signal stops the stream just the same
+    let (tx, rx) = oneshot::channel::<u32>();
+    let mut s = list().take_until(rx);
+    drop(tx);
+    assert_eq!(s.by_ref().collect().wait(), Ok(vec![]));
+    assert_eq!(s.take_result(), Some(Err(oneshot::Canceled)));
+}
+
+#[test]
+fn collect_until_err() {
+    let items = vec![Ok(1), Ok(2), Err("bad"), Ok(3)];
+    assert_done(|| iter_ok::<_, u32>(items).collect_until_err(),
+                Ok((vec![1, 2], Some((2, "bad")))));
+    assert_done(|| list().map(Ok::<_, ()>).collect_until_err(), Ok((vec![1, 2, 3], None)));
+    assert_done(|| err_list().map(Ok::<_, ()>).collect_until_err(), Err(3));
+}
+
+#[test]
+fn collect_into() {
+    use std::collections::HashMap;
+
+    let mut buf = Vec::with_capacity(8);
+    let ptr = buf.as_ptr();
+    buf = list().collect_into(buf).wait().unwrap();
+    assert_eq!(buf, vec![1, 2, 3]);
+    buf.clear();
+    buf = list().collect_into(buf).wait().unwrap();
+    assert_eq!(buf, vec![1, 2, 3]);
+    assert_eq!(buf.as_ptr(), ptr);
+    assert_eq!(buf.capacity(), 8);
+
+    let map = list().map(|x| (x, x * 2))
+        .collect_into(HashMap::new())
+        .wait()
+        .unwrap();
+    assert_eq!(map[&2], 4);
+
+    assert_done(|| err_list().collect_into(Vec::new()), Err(3));
+}
+
+#[test]
+fn count() {
+    assert_done(|| iter_ok::<_, u32>(1..6).count(), Ok(5));
+    assert_done(|| empty::<i32, u32>().count(), Ok(0));
+    assert_done(|| err_list().count(), Err(3));
+}
+
+#[test]
+fn last() {
+    assert_done(|| iter_ok::<_, u32>(1..6).last(), Ok(Some(5)));
+    assert_done(|| empty::<i32, u32>().last(), Ok(None));
+    assert_done(|| err_list().last(), Err(3));
+}
+
 #[test]
 fn take() {
     assert_done(|| list().take(2).collect(), Ok(vec![1, 2]));
@@ -193,6 +354,49 @@ fn fuse() {
     assert_eq!(stream.next(), None);
 }
 
+#[test]
+fn for_each_concurrent() {
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    let running = Rc::new(Cell::new(0));
+    let senders = Rc::new(RefCell::new(Vec::new()));
+    let (running2, senders2) = (running.clone(), senders.clone());
+    let f = iter_ok::<_, ()>(0..5).for_each_concurrent(2, move |_| {
+        let (tx, rx) = oneshot::channel::<()>();
+        senders2.borrow_mut().push(tx);
+        running2.set(running2.get() + 1);
+        let running = running2.clone();
+        rx.map_err(|_| ()).map(move |()| running.set(running.get() - 1))
+    });
+    let mut f = executor::spawn(f);
+
+    for _ in 0..4 {
+        assert_eq!(f.poll_future_notify(&notify_noop(), 0), Ok(Async::NotReady));
+        assert_eq!(running.get(), 2);
+        senders.borrow_mut().remove(0).send(()).unwrap();
+    }
+    assert_eq!(f.poll_future_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    assert_eq!(running.get(), 1);
+    assert_eq!(senders.borrow().len(), 1);
+    senders.borrow_mut().remove(0).send(()).unwrap();
+    assert_eq!(f.poll_future_notify(&notify_noop(), 0), Ok(Async::Ready(())));
+    assert_eq!(running.get(), 0);
+
+    // An error drops the futures which are still running
+    let (tx, rx) = oneshot::channel::<()>();
+    let mut rx = Some(rx);
+    let f = iter_ok::<_, u32>(0..2).for_each_concurrent(2, move |i| {
+        if i == 0 {
+            Box::new(rx.take().unwrap().map_err(|_| 0u32)) as Box<Future<Item = _, Error = _>>
+        } else {
+            Box::new(err(1))
+        }
+    });
+    assert_eq!(f.wait(), Err(1));
+    assert!(tx.is_canceled());
+}
+
 #[test]
 fn buffered() {
     let (tx, rx) = mpsc::channel(1);
@@ -232,6 +436,84 @@ fn buffered() {
     assert_eq!(rx.next(), None);
 }
 
+#[test]
+fn buffered_ordered() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let (a, b) = oneshot::channel::<u32>();
+    let mut slow = Some(b);
+    let pulled = Rc::new(Cell::new(0));
+    let pulled2 = pulled.clone();
+    let futures = iter_ok::<_, ()>(0..8).map(move |i| {
+        pulled2.set(i + 1);
+        if i == 1 {
+            Box::new(slow.take().unwrap().map_err(|_| ())) as Box<Future<Item = u32, Error = ()>>
+        } else {
+            Box::new(ok(i))
+        }
+    });
+
+    let mut rx = executor::spawn(futures.buffered_ordered(2, 3));
+    assert_eq!(rx.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(0))));
+
+    // The slow future only holds back new ones once 2 + 3 are outstanding.
+    assert_eq!(rx.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    assert_eq!(pulled.get(), 6);
+
+    a.send(1).unwrap();
+    let rest = rx.into_inner().collect().wait();
+    assert_eq!(rest, Ok(vec![1, 2, 3, 4, 5, 6, 7]));
+}
+
+#[test]
+#[should_panic]
+fn buffered_ordered_panics_on_zero_concurrency() {
+    let _ = iter_ok::<_, ()>(vec![ok::<u32, ()>(1)]).buffered_ordered(0, 1);
+}
+
+#[test]
+fn try_buffered_stops_at_first_error() {
+    let (a, b) = oneshot::channel::<u32>();
+    let (c, d) = oneshot::channel::<u32>();
+    let futures = vec![
+        Box::new(b.map_err(|_| 0u32)) as Box<Future<Item = u32, Error = u32> + Send>,
+        Box::new(err(7)),
+        Box::new(d.map_err(|_| 0u32)),
+    ];
+
+    let mut rx = iter_ok::<_, u32>(futures).try_buffered(3).wait();
+    assert_eq!(rx.next(), Some(Err(7)));
+    assert!(a.is_canceled());
+    assert!(c.is_canceled());
+    assert_eq!(rx.next(), None);
+}
+
+#[test]
+fn map_concurrent() {
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..3).map(|_| oneshot::channel::<u32>()).unzip();
+    let mut rxs = rxs.into_iter();
+    let s = iter_ok::<_, u32>(vec![1, 2, 3])
+        .map_concurrent(3, move |_| rxs.next().unwrap().map_err(|_| 0));
+    let mut s = executor::spawn(s);
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+
+    // Complete the mappings in reverse, results still come out in order.
+    for (i, tx) in txs.into_iter().enumerate().rev() {
+        tx.send(i as u32 * 10).unwrap();
+    }
+    let s = s.into_inner();
+    assert_eq!(s.collect().wait(), Ok(vec![0, 10, 20]));
+
+    let mut s = iter_ok::<_, u32>(vec![1, 2, 3])
+        .map_concurrent(2, |x| if x == 2 { err(x) } else { ok(x) })
+        .wait();
+    // The mapping of 1 has completed as well, but its result is discarded
+    // along with everything else in flight once the error shows up
+    assert_eq!(s.next(), Some(Err(2)));
+    assert_eq!(s.next(), None);
+}
+
 #[test]
 fn unordered() {
     let (tx, rx) = mpsc::channel(1);
@@ -271,6 +553,155 @@ fn unordered() {
     assert_eq!(rx.next(), None);
 }
 
+#[test]
+fn left_right_stream() {
+    let pick = |n: i32| if n % 2 == 0 {
+        list().left_stream()
+    } else {
+        list().map(move |x| x * n).right_stream()
+    };
+    assert_done(|| pick(2).collect(), Ok(vec![1, 2, 3]));
+    assert_done(|| pick(3).collect(), Ok(vec![3, 6, 9]));
+}
+
+#[test]
+fn until_error() {
+    let mut s = err_list().until_error().wait();
+    assert_eq!(s.next(), Some(Ok(1)));
+    assert_eq!(s.next(), Some(Ok(2)));
+    assert_eq!(s.next(), None);
+    assert_eq!(s.into_inner().into_error(), Some(3));
+
+    let mut s = list().until_error();
+    assert_done(|| (&mut s).collect(), Ok(vec![1, 2, 3]));
+    assert_eq!(s.into_error(), None);
+}
+
+#[test]
+fn on_complete() {
+    use std::cell::Cell;
+
+    let calls = Cell::new(0);
+    assert_done(|| list().on_complete(|| calls.set(calls.get() + 1)).collect(),
+                Ok(vec![1, 2, 3]));
+    assert_eq!(calls.get(), 1);
+
+    let calls = Cell::new(0);
+    assert_done(|| err_list().on_complete(|| calls.set(calls.get() + 1)).collect(),
+                Err(3));
+    assert_eq!(calls.get(), 1);
+
+    // `take` is done after two items, and drops the stream when it's dropped
+    let calls = Cell::new(0);
+    let mut s = list().on_complete(|| calls.set(calls.get() + 1)).take(2).wait();
+    assert_eq!(s.next(), Some(Ok(1)));
+    assert_eq!(s.next(), Some(Ok(2)));
+    assert_eq!(s.next(), None);
+    assert_eq!(calls.get(), 0);
+    drop(s);
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn buffer_hysteresis() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let pulled = Rc::new(Cell::new(0));
+    let pulled2 = pulled.clone();
+    let source = iter_ok::<_, ()>(0..10).inspect(move |_| pulled2.set(pulled2.get() + 1));
+    let mut s = executor::spawn(source.buffer_hysteresis(4, 1));
+
+    // Filled up to the high watermark, then paused until drained to the low one
+    let mut next = || s.poll_stream_notify(&notify_noop(), 0);
+    assert_eq!(next(), Ok(Async::Ready(Some(0))));
+    assert_eq!(pulled.get(), 4);
+    assert_eq!(next(), Ok(Async::Ready(Some(1))));
+    assert_eq!(next(), Ok(Async::Ready(Some(2))));
+    assert_eq!(pulled.get(), 4);
+    assert_eq!(next(), Ok(Async::Ready(Some(3))));
+    assert_eq!(pulled.get(), 7);
+
+    // The rest comes out in order, buffered or not
+    assert_eq!(s.wait_stream(), Some(Ok(4)));
+    assert_eq!(s.into_inner().collect().wait(), Ok(vec![5, 6, 7, 8, 9]));
+}
+
+#[test]
+#[should_panic]
+fn buffer_hysteresis_panics_on_bad_watermarks() {
+    let _ = list().buffer_hysteresis(2, 2);
+}
+
+#[test]
+fn chain_lazy() {
+    use std::cell::Cell;
+
+    let built = Cell::new(false);
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let mut s = executor::spawn(rx.map_err(|_| 0u32).chain_lazy(|| {
+        built.set(true);
+        iter_ok(vec![3])
+    }));
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(1))));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    assert!(!built.get());
+    drop(tx);
+    assert_eq!(s.wait_stream(), Some(Ok(3)));
+    assert!(built.get());
+    assert_eq!(s.wait_stream(), None);
+
+    assert_done(|| err_list().chain_lazy(|| -> stream::Empty<i32, u32> { panic!() }).collect(),
+                Err(3));
+}
+
+#[test]
+fn chain_with() {
+    // Fetch pages of two items after the last item of the previous page
+    fn page(after: i32) -> stream::IterOk<std::vec::IntoIter<i32>, u32> {
+        iter_ok(vec![after + 1, after + 2])
+    }
+    let pages = page(0).chain_with(|last| page(last.unwrap()))
+        .chain_with(|last| page(last.unwrap()));
+    assert_done(|| pages.collect(), Ok(vec![1, 2, 3, 4, 5, 6]));
+
+    assert_done(|| empty::<i32, u32>().chain_with(iter_ok).collect(),
+                Ok(vec![]));
+    assert_done(|| err_list().chain_with(|_| -> stream::Empty<i32, u32> { panic!() }).collect(),
+                Err(3));
+}
+
+#[test]
+fn flat_map() {
+    assert_done(|| list().flat_map(|n| iter_ok::<_, u32>(0..n)).collect(),
+                Ok(vec![0, 0, 1, 0, 1, 2]));
+    assert_done(|| list().flat_map(|n| iter_ok::<_, u32>(vec![n; n as usize])).collect(),
+                Ok(vec![1, 2, 2, 3, 3, 3]));
+    assert_done(|| err_list().flat_map(|n| iter_ok::<_, u32>(0..n)).collect(), Err(3));
+    assert_done(|| list().flat_map(|n| if n == 2 { err_list() } else { list() }).collect(),
+                Err(3));
+}
+
+#[test]
+fn try_flatten() {
+    let streams = vec![Ok(iter_ok::<_, u32>(vec![1, 2])), Err(3), Ok(iter_ok(vec![4]))];
+    let mut s = iter_ok::<_, u32>(streams).try_flatten();
+    sassert_next(&mut s, 1);
+    sassert_next(&mut s, 2);
+    sassert_err(&mut s, 3);
+    sassert_next(&mut s, 4);
+    sassert_done(&mut s);
+
+    // Errors of the inner streams are passed through too
+    let streams = vec![Ok(iter_result(vec![Ok(1), Err(2u32)])), Ok(iter_result(vec![Ok(3)]))];
+    let mut s = iter_ok::<_, u32>(streams).try_flatten();
+    sassert_next(&mut s, 1);
+    sassert_err(&mut s, 2);
+    sassert_next(&mut s, 3);
+    sassert_done(&mut s);
+}
+
 #[test]
 fn zip() {
     assert_done(|| list().zip(list()).collect(),
@@ -284,6 +715,300 @@ fn zip() {
                 Ok(vec![(1, 2), (2, 3), (3, 4)]));
 }
 
+#[test]
+fn zip3() {
+    let timestamps = iter_ok::<_, u32>(vec![10, 20, 30, 40]);
+    let sources = iter_ok(vec!["a", "b"]);
+    assert_done(|| list().zip3(timestamps, sources).collect(),
+                Ok(vec![(1, 10, "a"), (2, 20, "b")]));
+    assert_done(|| err_list().zip3(list(), list()).collect(), Err(3));
+}
+
+#[test]
+fn zip_all() {
+    let streams = vec![iter_ok::<_, ()>(vec![1, 2, 3]), iter_ok(vec![4, 5]), iter_ok(vec![6, 7, 8])];
+    assert_done(|| stream::zip_all(streams).collect(),
+                Ok(vec![vec![1, 4, 6], vec![2, 5, 7]]));
+    assert_done(|| stream::zip_all(Vec::<Box<Stream<Item = u32, Error = u32>>>::new()).collect(),
+                Ok(vec![]));
+}
+
+#[test]
+fn zip_latest() {
+    let (data_tx, data_rx) = mpsc::unbounded::<i32>();
+    let (config_tx, config_rx) = mpsc::unbounded::<&str>();
+    let mut s = executor::spawn(data_rx.zip_latest(config_rx));
+
+    // Nothing comes out until both sides have produced something
+    data_tx.unbounded_send(1).unwrap();
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+
+    config_tx.unbounded_send("a").unwrap();
+    data_tx.unbounded_send(2).unwrap();
+    data_tx.unbounded_send(3).unwrap();
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some((1, "a")))));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some((2, "a")))));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some((3, "a")))));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+
+    config_tx.unbounded_send("b").unwrap();
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some((3, "b")))));
+    drop(config_tx);
+    data_tx.unbounded_send(4).unwrap();
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some((4, "b")))));
+    drop(data_tx);
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(None)));
+}
+
+#[test]
+fn zip_latest_ends_if_one_side_never_produces() {
+    assert_done(|| list().zip_latest(empty::<i32, u32>()).collect(), Ok(vec![]));
+    assert_done(|| err_list().zip_latest(list()).collect(), Err(3));
+}
+
+#[test]
+fn zip_latest_yields_while_one_side_is_always_ready() {
+    let (tx, rx) = mpsc::unbounded::<&str>();
+    let mut s = executor::spawn(iter_ok(0..).zip_latest(rx));
+
+    // The endless side is ready every time, but there's nothing to pair it
+    // with yet
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+
+    tx.unbounded_send("a").unwrap();
+    match s.poll_stream_notify(&notify_noop(), 0) {
+        Ok(Async::Ready(Some((_, "a")))) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn merge_ordered() {
+    let streams = vec![iter_ok::<_, ()>(vec![1, 4, 7]), iter_ok(vec![2, 3, 8])];
+    assert_done(|| stream::merge_ordered(streams, |x| *x).collect(),
+                Ok(vec![1, 2, 3, 4, 7, 8]));
+}
+
+#[test]
+fn merge_ordered_waits_for_every_head() {
+    let (tx1, rx1) = mpsc::unbounded::<(u32, &str)>();
+    let (tx2, rx2) = mpsc::unbounded::<(u32, &str)>();
+    let mut s = executor::spawn(stream::merge_ordered(vec![rx1, rx2], |&(t, _)| t));
+
+    tx1.unbounded_send((1, "a")).unwrap();
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    tx2.unbounded_send((1, "b")).unwrap();
+    tx2.unbounded_send((5, "c")).unwrap();
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some((1, "a")))));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+
+    drop(tx1);
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some((1, "b")))));
+    drop(tx2);
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some((5, "c")))));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(None)));
+}
+
+#[test]
+fn tee() {
+    let mut seen = Vec::new();
+    assert_eq!(iter_ok::<_, ()>(1..4).tee(&mut seen).collect().wait(), Ok(vec![1, 2, 3]));
+    assert_eq!(seen, vec![1, 2, 3]);
+
+    // An item is held back until the side sink has room for it
+    let (tx, rx) = mpsc::channel::<i32>(0);
+    let mut s = executor::spawn(iter_ok::<_, ()>(1..3).tee(tx.sink_map_err(|_| ())));
+    let mut rx = executor::spawn(rx);
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(1))));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    assert_eq!(rx.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(1))));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(2))));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(None)));
+    assert_eq!(rx.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(2))));
+}
+
+#[test]
+fn sample() {
+    use std::time::Duration;
+
+    let secs = Duration::from_secs;
+    let timer = MockTimer::new();
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let mut s = executor::spawn(rx.sample(secs(10), &timer));
+
+    for i in 1..4 {
+        tx.unbounded_send(i).unwrap();
+    }
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    timer.set(secs(10));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(3))));
+
+    // Nothing new arrived, so this tick is skipped
+    timer.set(secs(20));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+
+    tx.unbounded_send(4).unwrap();
+    tx.unbounded_send(5).unwrap();
+    drop(tx);
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    timer.set(secs(30));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(5))));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(None)));
+}
+
+#[test]
+fn sample_returns_while_stream_is_always_ready() {
+    use std::time::Duration;
+
+    let secs = Duration::from_secs;
+    let timer = MockTimer::new();
+    let mut s = executor::spawn(iter_ok::<_, ()>(0..).sample(secs(10), &timer));
+
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    timer.set(secs(10));
+    match s.poll_stream_notify(&notify_noop(), 0) {
+        Ok(Async::Ready(Some(n))) => assert!(n > 0),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn debounce() {
+    use std::time::Duration;
+
+    let millis = Duration::from_millis;
+    let timer = MockTimer::new();
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let mut s = executor::spawn(rx.debounce(millis(100), &timer));
+
+    for i in 1..4 {
+        tx.unbounded_send(i).unwrap();
+    }
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+
+    // A new item restarts the quiet period, and once it's over the last item
+    // is let through
+    timer.set(millis(50));
+    tx.unbounded_send(4).unwrap();
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    timer.set(millis(120));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    timer.set(millis(150));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(4))));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+
+    // A pending item is flushed when the stream ends
+    tx.unbounded_send(5).unwrap();
+    drop(tx);
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(5))));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(None)));
+}
+
+#[test]
+fn debounce_returns_while_stream_is_always_ready() {
+    use std::time::Duration;
+
+    let timer = MockTimer::new();
+    let s = iter_ok::<_, ()>(0..).debounce(Duration::from_millis(100), &timer);
+    let mut s = executor::spawn(s);
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+}
+
+#[test]
+fn debounce_leading() {
+    use std::time::Duration;
+
+    let millis = Duration::from_millis;
+    let timer = MockTimer::new();
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let mut s = executor::spawn(rx.debounce_leading(millis(100), &timer));
+
+    for i in 1..4 {
+        tx.unbounded_send(i).unwrap();
+    }
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(1))));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+
+    // Once the burst is over the next item starts a new one
+    timer.set(millis(100));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    tx.unbounded_send(4).unwrap();
+    tx.unbounded_send(5).unwrap();
+    drop(tx);
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(4))));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(None)));
+}
+
+#[test]
+fn interval() {
+    use std::time::Duration;
+    use futures::stream::MissedTicks;
+
+    let secs = Duration::from_secs;
+    let timer = MockTimer::new();
+
+    let mut s = executor::spawn(stream::interval(secs(10), &timer));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    timer.set(secs(12));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(()))));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+
+    // Deadlines stay on the original schedule despite the late tick
+    timer.set(secs(20));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(()))));
+
+    // Ticks at 30 and 40 were missed and get coalesced
+    timer.set(secs(45));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(()))));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    timer.set(secs(50));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(()))));
+
+    let timer = MockTimer::new();
+    let s = stream::interval_with(secs(10), &timer, MissedTicks::CatchUp);
+    let mut s = executor::spawn(s);
+    timer.set(secs(35));
+    for _ in 0..3 {
+        assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(()))));
+    }
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+}
+
+#[test]
+fn take_for_and_skip_for() {
+    use std::time::Duration;
+
+    let secs = Duration::from_secs;
+    let timer = MockTimer::new();
+
+    // A source which never runs dry only yields items until the time is up
+    let mut n = 0;
+    let source = stream::repeat::<_, ()>(1).map(move |x| { n += x; n });
+    let mut s = executor::spawn(source.take_for(secs(5), &timer));
+    for i in 1..4 {
+        assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(i))));
+    }
+    timer.set(secs(5));
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(None)));
+
+    let s = iter_ok::<_, ()>(vec![1, 2]).take_for(secs(5), &timer);
+    assert_eq!(s.collect().wait(), Ok(vec![1, 2]));
+
+    let timer = MockTimer::new();
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let mut s = executor::spawn(rx.skip_for(secs(5), &timer));
+    tx.unbounded_send(1).unwrap();
+    tx.unbounded_send(2).unwrap();
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    timer.set(secs(5));
+    tx.unbounded_send(3).unwrap();
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(3))));
+    drop(tx);
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(None)));
+}
+
 #[test]
 fn peek() {
     struct Peek {
@@ -310,6 +1035,36 @@ fn peek() {
     }.wait().unwrap()
 }
 
+#[test]
+fn peek_buffers_item() {
+    use std::cell::Cell;
+
+    let polled = Cell::new(0);
+    let mut s = iter_ok::<_, u32>(vec![1, 2])
+        .inspect(|_| polled.set(polled.get() + 1))
+        .peekable();
+
+    assert_eq!(s.peek(), Ok(Async::Ready(Some(&1))));
+    assert_eq!(s.peek(), Ok(Async::Ready(Some(&1))));
+    assert_eq!(polled.get(), 1);
+    assert_eq!(s.poll(), Ok(Async::Ready(Some(1))));
+    assert_eq!(polled.get(), 1);
+    assert_eq!(s.peek(), Ok(Async::Ready(Some(&2))));
+    assert_eq!(s.poll(), Ok(Async::Ready(Some(2))));
+    assert_eq!(s.peek(), Ok(Async::Ready(None)));
+    assert_eq!(s.poll(), Ok(Async::Ready(None)));
+}
+
+#[test]
+fn peekable_push_back() {
+    let (item, mut s) = iter_ok::<_, u32>(vec![1, 2]).peekable().into_future().wait().ok().unwrap();
+    assert_eq!(item, Some(1));
+    assert_eq!(s.push_back(1), Ok(()));
+    assert_eq!(s.push_back(0), Err(0));
+    assert_eq!(s.peek(), Ok(Async::Ready(Some(&1))));
+    assert_eq!(s.collect().wait(), Ok(vec![1, 2]));
+}
+
 #[test]
 fn wait() {
     assert_eq!(list().wait().collect::<Result<Vec<_>, _>>(),
@@ -334,6 +1089,52 @@ fn chunks_panic_on_cap_zero() {
     let _ = list().chunks(0);
 }
 
+#[test]
+fn windows() {
+    assert_done(|| iter_ok::<_, u32>(vec![1, 2, 3, 4]).windows(2).collect(),
+                Ok(vec![vec![1, 2], vec![2, 3], vec![3, 4]]));
+    assert_done(|| list().windows(3).collect(), Ok(vec![vec![1, 2, 3]]));
+    assert_done(|| list().windows(4).collect(), Ok(vec![]));
+    let mut list = executor::spawn(err_list().windows(2));
+    let i = list.wait_stream().unwrap().unwrap();
+    assert_eq!(i, vec![1, 2]);
+    let i = list.wait_stream().unwrap().unwrap_err();
+    assert_eq!(i, 3);
+}
+
+#[test]
+#[should_panic]
+fn windows_panic_on_size_zero() {
+    let _ = list().windows(0);
+}
+
+#[test]
+fn cycle() {
+    let pairs = stream::repeat::<_, u32>(1).zip(iter_ok(vec!['a', 'b', 'c']).cycle());
+    assert_done(|| pairs.take(7).collect(),
+                Ok(vec![(1, 'a'), (1, 'b'), (1, 'c'), (1, 'a'), (1, 'b'), (1, 'c'), (1, 'a')]));
+    assert_done(|| list().cycle_n(2).collect(), Ok(vec![1, 2, 3, 1, 2, 3]));
+    assert_done(|| list().cycle_n(0).collect(), Ok(vec![]));
+    assert_done(|| empty::<i32, u32>().cycle().collect(), Ok(vec![]));
+    assert_done(|| err_list().cycle().collect(), Err(3));
+}
+
+#[test]
+fn future_into_stream_chain() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let s = rx.map_err(|_| 0u32).into_stream().chain(list());
+    let mut s = executor::spawn(s);
+    assert_eq!(s.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    tx.send(0).unwrap();
+    assert_eq!(s.wait_stream(), Some(Ok(0)));
+    assert_eq!(s.wait_stream(), Some(Ok(1)));
+    assert_eq!(s.wait_stream(), Some(Ok(2)));
+    assert_eq!(s.wait_stream(), Some(Ok(3)));
+    assert_eq!(s.wait_stream(), None);
+
+    assert_done(|| err::<i32, u32>(4).into_stream().chain(list()).collect(), Err(4));
+}
+
 #[test]
 fn select() {
     let a = iter_ok::<_, u32>(vec![1, 2, 3]);
@@ -381,7 +1182,10 @@ fn concat2() {
     assert_done(move || b.concat2(), Err(()));
 
     let c = empty::<Vec<()>, ()>();
-    assert_done(move || c.concat2(), Ok(vec![]))
+    assert_done(move || c.concat2(), Ok(vec![]));
+
+    let d = iter_ok::<_, ()>(vec![vec![1, 2], vec![3], vec![4, 5]]);
+    assert_done(move || d.concat2(), Ok(vec![1, 2, 3, 4, 5]));
 }
 
 #[test]
@@ -398,3 +1202,36 @@ fn stream_poll_fn() {
 
     assert_eq!(read_stream.wait().count(), 5);
 }
+
+#[test]
+fn boxed_local() {
+    use std::rc::Rc;
+
+    let shared = Rc::new(10);
+    let s: stream::LocalBoxStream<i32, u32> = list().map(move |x| x * *shared).boxed_local();
+    assert_done(|| s.collect(), Ok(vec![10, 20, 30]));
+}
+
+#[test]
+fn stream_poll_fn_stateful() {
+    use std::rc::Rc;
+
+    // The counter is kept alongside an `Rc` so we can tell when it's dropped
+    let alive = Rc::new(());
+    let state = (3usize, alive.clone());
+    let mut countdown = poll_fn_stateful(state, |state| -> Poll<Option<usize>, ()> {
+        if state.0 == 0 {
+            return Ok(Async::Ready(None));
+        }
+        state.0 -= 1;
+        Ok(Async::Ready(Some(state.0)))
+    }).wait();
+
+    assert_eq!(countdown.next(), Some(Ok(2)));
+    assert_eq!(countdown.next(), Some(Ok(1)));
+    assert_eq!(countdown.next(), Some(Ok(0)));
+    assert_eq!(Rc::strong_count(&alive), 2);
+    assert_eq!(countdown.next(), None);
+    assert_eq!(Rc::strong_count(&alive), 1);
+    assert_eq!(countdown.next(), None);
+}
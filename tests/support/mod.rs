@@ -1,14 +1,18 @@
 #![allow(dead_code)]
 
+use std::cell::Cell;
 use std::fmt;
+use std::rc::Rc;
 use std::sync::Arc;
 use std::thread;
+use std::time::{Duration, Instant};
 
 use futures::{Future, IntoFuture, Async, Poll};
 use futures::future::FutureResult;
 use futures::stream::Stream;
 use futures::executor::{self, NotifyHandle, Notify};
 use futures::task;
+use futures::timer::Timer;
 
 pub mod local_executor;
 
@@ -132,3 +136,58 @@ pub fn delay_future<F>(f: F) -> DelayFuture<F::Future>
     DelayFuture(f.into_future(), false)
 }
 
+/// A `Timer` whose time only moves forward when told to.
+///
+/// Its delays don't notify the task waiting on them, they have to be polled
+/// again after the time has been moved.
+pub struct MockTimer {
+    start: Instant,
+    now: Rc<Cell<Instant>>,
+}
+
+pub struct MockDelay {
+    now: Rc<Cell<Instant>>,
+    at: Instant,
+}
+
+impl MockTimer {
+    pub fn new() -> MockTimer {
+        let start = Instant::now();
+        MockTimer { start: start, now: Rc::new(Cell::new(start)) }
+    }
+
+    /// The time at which this timer was created.
+    pub fn start(&self) -> Instant {
+        self.start
+    }
+
+    /// Sets the current time to `since` after the start.
+    pub fn set(&self, since: Duration) {
+        self.now.set(self.start + since);
+    }
+}
+
+impl Timer for MockTimer {
+    type Delay = MockDelay;
+
+    fn delay(&self, at: Instant) -> MockDelay {
+        MockDelay { now: self.now.clone(), at: at }
+    }
+
+    fn now(&self) -> Instant {
+        self.now.get()
+    }
+}
+
+impl Future for MockDelay {
+    type Item = ();
+    type Error = ();
+
+    fn poll(&mut self) -> Poll<(), ()> {
+        if self.now.get() >= self.at {
+            Ok(Async::Ready(()))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
+}
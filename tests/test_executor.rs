@@ -0,0 +1,55 @@
+extern crate futures;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use futures::prelude::*;
+use futures::future::{self, poll_fn};
+use futures::executor::TestExecutor;
+use futures::sync::oneshot;
+
+#[test]
+fn join_polls_both_once() {
+    let polls = Rc::new((Cell::new(0), Cell::new(0)));
+    let (p1, p2) = (polls.clone(), polls.clone());
+    let a = poll_fn(move || -> Poll<(), ()> {
+        p1.0.set(p1.0.get() + 1);
+        Ok(Async::Ready(()))
+    });
+    let b = poll_fn(move || -> Poll<(), ()> {
+        p2.1.set(p2.1.get() + 1);
+        Ok(Async::Ready(()))
+    });
+
+    let mut exec = TestExecutor::new();
+    let id = exec.spawn(a.join(b).map(|_| ()));
+    assert_eq!(exec.step(), Some(id));
+    assert!(exec.is_done(id));
+    assert_eq!((polls.0.get(), polls.1.get()), (1, 1));
+    assert_eq!(exec.step(), None);
+    assert!(exec.notifications().is_empty());
+}
+
+#[test]
+fn steps_follow_notifications() {
+    let (tx1, rx1) = oneshot::channel::<()>();
+    let (tx2, rx2) = oneshot::channel::<()>();
+
+    let mut exec = TestExecutor::new();
+    let first = exec.spawn(rx1.map_err(|_| ()));
+    let second = exec.spawn(rx2.map_err(|_| ()));
+    let third = exec.spawn(future::ok(()));
+    assert_eq!(exec.run_until_stalled(), 3);
+    assert!(exec.is_done(third));
+
+    // Tasks are polled in the order they were woken up
+    tx2.send(()).unwrap();
+    tx1.send(()).unwrap();
+    assert_eq!(exec.notifications(), vec![second, first]);
+    assert_eq!(exec.step(), Some(second));
+    assert!(exec.is_done(second));
+    assert!(!exec.is_done(first));
+    assert_eq!(exec.step(), Some(first));
+    assert!(exec.is_done(first));
+    assert_eq!(exec.step(), None);
+}
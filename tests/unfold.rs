@@ -50,3 +50,37 @@ fn unfold_err1() {
     // An error was generated by the stream, it will then finish
     sassert_done(&mut stream);
 }
+
+#[test]
+fn unfold_result_fibonacci() {
+    let mut stream = stream::unfold_result((0u8, 1u8), |(a, b)| {
+        match a.checked_add(b) {
+            Some(c) => delay_future(Ok(Some((a, (b, c))))),
+            None => delay_future(Err(a)),
+        }
+    });
+    for &n in &[0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89] {
+        sassert_empty(&mut stream);
+        sassert_next(&mut stream, n);
+    }
+    sassert_empty(&mut stream);
+    sassert_err(&mut stream, 144);
+
+    // An error was generated by the stream, it will then finish
+    sassert_done(&mut stream);
+}
+
+#[test]
+fn try_unfold_ends_on_none() {
+    let mut stream = stream::try_unfold(0, |state| {
+        if state <= 2 {
+            Ok::<_, ()>(Some((state * 2, state + 1)))
+        } else {
+            Ok(None)
+        }
+    });
+    sassert_next(&mut stream, 0);
+    sassert_next(&mut stream, 2);
+    sassert_next(&mut stream, 4);
+    sassert_done(&mut stream);
+}
@@ -23,6 +23,14 @@ fn poll_cancel() {
     assert!(tx.poll_cancel().unwrap().is_ready());
 }
 
+#[test]
+fn send_after_poll_cancel() {
+    let (mut tx, rx) = channel::<()>();
+    drop(rx);
+    assert!(tx.poll_cancel().unwrap().is_ready());
+    assert_eq!(tx.send(()), Err(()));
+}
+
 #[test]
 fn tx_complete_rx_unparked() {
     let (tx, rx) = channel();
@@ -53,6 +53,18 @@ fn mpsc_tx_notready() {
     }).wait().unwrap();
 }
 
+#[test]
+fn mpsc_tx_poll_ready() {
+    let (tx, mut rx) = mpsc::channel::<i32>(1);
+    let mut tx = tx.send(1).wait().unwrap();
+    lazy(move || {
+        assert!(tx.poll_ready().unwrap().is_not_ready());
+        assert_eq!(rx.poll().unwrap(), Async::Ready(Some(1)));
+        assert!(tx.poll_ready().unwrap().is_ready());
+        Ok(()) as Result<(), ()>
+    }).wait().unwrap();
+}
+
 #[test]
 fn mpsc_tx_err() {
     let (tx, _) = mpsc::channel::<i32>(1);
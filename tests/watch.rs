@@ -0,0 +1,73 @@
+extern crate futures;
+
+use futures::prelude::*;
+use futures::executor;
+use futures::sync::watch;
+
+mod support;
+use support::*;
+
+#[test]
+fn slow_receiver_sees_latest() {
+    let (tx, rx) = watch::channel(0);
+    let mut rx = executor::spawn(rx);
+    assert_eq!(rx.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(0))));
+    assert_eq!(rx.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+
+    tx.set(1).unwrap();
+    tx.set(2).unwrap();
+    assert_eq!(rx.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(2))));
+    assert_eq!(rx.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+
+    drop(tx);
+    assert_eq!(rx.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(None)));
+}
+
+#[test]
+fn late_receiver_sees_current_value() {
+    let (tx, rx) = watch::channel("a");
+    let mut rx = executor::spawn(rx);
+    assert_eq!(rx.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some("a"))));
+    tx.set("b").unwrap();
+
+    let late = rx.get_ref().clone();
+    assert_eq!(*late.borrow(), "b");
+    drop(tx);
+    assert_eq!(late.collect().wait(), Ok(vec!["b"]));
+    assert_eq!(rx.wait_stream(), Some(Ok("b")));
+}
+
+#[test]
+fn borrow_does_not_block_updates() {
+    let (tx, rx) = watch::channel(0);
+    let mut rx = executor::spawn(rx);
+    let value = rx.get_ref().borrow();
+
+    // Both of these would lock up if the borrow kept the channel locked
+    tx.set(1).unwrap();
+    assert_eq!(rx.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(1))));
+
+    assert_eq!(*value, 0);
+    assert_eq!(*rx.get_ref().borrow(), 1);
+}
+
+#[test]
+fn set_notifies_receivers() {
+    let (tx, rx) = watch::channel(0);
+    let mut rx = executor::spawn(rx.skip(1));
+    assert_eq!(rx.poll_stream_notify(&notify_panic(), 0), Ok(Async::NotReady));
+    assert_eq!(rx.poll_stream_notify(&notify_noop(), 0), Ok(Async::NotReady));
+    tx.set(1).unwrap();
+    assert_eq!(rx.poll_stream_notify(&notify_noop(), 0), Ok(Async::Ready(Some(1))));
+}
+
+#[test]
+fn set_fails_without_receivers() {
+    let (tx, rx) = watch::channel(0);
+    let rx2 = rx.clone();
+    drop(rx);
+    assert!(!tx.is_canceled());
+    drop(rx2);
+    assert!(tx.is_canceled());
+    assert_eq!(tx.set(1), Err(1));
+}